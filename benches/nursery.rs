@@ -0,0 +1,167 @@
+//! Compare `Nursery` to spawning on the executor directly and managing the `JoinHandle`s in a
+//! `FuturesUnordered`, on different executors. This catches regressions in the overhead of
+//! `nurse_obj` and `NurseryStream::poll_next`.
+//!
+//! - spawn       : throughput of spawning many small tasks and collecting their outputs.
+//! - join_latency: round trip of a single task, from spawning until the output is yielded.
+//! - few_long    : a few tasks that yield to the executor many times before finishing.
+//!
+//! $ cargo bench --bench nursery
+//
+use
+{
+	async_executors :: { AsyncStd, TokioCt, TokioTp, SpawnHandle, SpawnHandleExt                                 } ,
+	async_nursery   :: { Nursery, NurseExt                                                                      } ,
+	criterion       :: { criterion_group, criterion_main, Criterion, BenchmarkId                                } ,
+	futures         :: { future::{ poll_fn, LocalBoxFuture }, stream::FuturesUnordered, StreamExt, FutureExt } ,
+	std             :: { task::Poll                                                                             } ,
+};
+
+
+
+// How much work each benchmark does: the number of tasks and how many times each task yields.
+//
+const SPAWN   : ( usize, usize ) = ( 1000, 0    );
+const LATENCY : ( usize, usize ) = ( 1   , 0    );
+const FEW_LONG: ( usize, usize ) = ( 10  , 1000 );
+
+
+
+async fn yield_now()
+{
+	let mut yielded = false;
+
+	poll_fn( |cx|
+	{
+		if yielded { return Poll::Ready(()) }
+
+		yielded = true;
+		cx.waker().wake_by_ref();
+		Poll::Pending
+
+	}).await
+}
+
+
+
+async fn work( i: usize, yields: usize ) -> usize
+{
+	for _ in 0..yields
+	{
+		yield_now().await;
+	}
+
+	i
+}
+
+
+
+async fn nursery<E>( exec: E, (tasks, yields): (usize, usize) )
+
+	where E: SpawnHandle<usize> + 'static
+{
+	let (nursery, output) = Nursery::new( exec );
+
+	for i in 0..tasks
+	{
+		nursery.nurse( work( i, yields ) ).expect( "nurse" );
+	}
+
+	drop( nursery );
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( tasks, outputs.len() );
+}
+
+
+
+async fn nursery_ordered<E>( exec: E, (tasks, yields): (usize, usize) )
+
+	where E: SpawnHandle<usize> + 'static
+{
+	let (nursery, output) = Nursery::new_ordered( exec );
+
+	for i in 0..tasks
+	{
+		nursery.nurse( work( i, yields ) ).expect( "nurse" );
+	}
+
+	drop( nursery );
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( tasks, outputs.len() );
+}
+
+
+
+async fn manual<E>( exec: E, (tasks, yields): (usize, usize) )
+
+	where E: SpawnHandle<usize>
+{
+	let unordered: FuturesUnordered<_> = (0..tasks)
+
+		.map( |i| exec.spawn_handle( work( i, yields ) ).expect( "spawn" ) )
+		.collect()
+	;
+
+	let outputs: Vec<usize> = unordered.collect().await;
+
+	assert_eq!( tasks, outputs.len() );
+}
+
+
+
+fn executor<E>( c: &mut Criterion, name: &str, exec: E, block_on: impl Fn( LocalBoxFuture<'static, ()> ) )
+
+	where E: SpawnHandle<usize> + Clone + 'static
+{
+	let cases =
+	[
+		( "spawn"       , SPAWN    ),
+		( "join_latency", LATENCY  ),
+		( "few_long"    , FEW_LONG ),
+	];
+
+	for (group, work) in cases
+	{
+		let mut group = c.benchmark_group( group );
+
+		group.bench_function( BenchmarkId::new( "nursery"        , name ), |b| b.iter( || block_on( nursery        ( exec.clone(), work ).boxed_local() ) ) );
+		group.bench_function( BenchmarkId::new( "nursery_ordered", name ), |b| b.iter( || block_on( nursery_ordered( exec.clone(), work ).boxed_local() ) ) );
+		group.bench_function( BenchmarkId::new( "manual"         , name ), |b| b.iter( || block_on( manual         ( exec.clone(), work ).boxed_local() ) ) );
+
+		group.finish();
+	}
+}
+
+
+
+fn async_std( c: &mut Criterion )
+{
+	executor( c, "async_std", AsyncStd, AsyncStd::block_on );
+}
+
+
+
+fn tokio_tp( c: &mut Criterion )
+{
+	let exec = TokioTp::new().expect( "create tokio threadpool" );
+
+	executor( c, "tokio_tp", exec.clone(), |fut| exec.block_on( fut ) );
+}
+
+
+
+fn tokio_ct( c: &mut Criterion )
+{
+	let exec = TokioCt::new().expect( "create tokio current thread" );
+
+	executor( c, "tokio_ct", exec.clone(), |fut| exec.block_on( fut ) );
+}
+
+
+
+criterion_group!( benches, async_std, tokio_tp, tokio_ct );
+criterion_main! ( benches                                 );
@@ -0,0 +1,233 @@
+//! Compare the registry through which `Nursery` hands it's tasks to the `NurseryStream` with the unbounded
+//! channel it replaced. Only the hand over is measured, so messages are plain numbers rather than tasks.
+//!
+//! - send_all: send all messages, then receive them, like tasks spawned before polling the stream.
+//! - interleaved: receive every message right after it was sent, like a stream that keeps up with spawning.
+//! - threads: send from 4 threads while receiving on this one.
+//!
+//! $ cargo bench --bench registry
+//
+use
+{
+	criterion :: { criterion_group, criterion_main, Criterion, BenchmarkId                   } ,
+	futures   :: { executor::block_on, future::poll_fn, channel::mpsc, StreamExt, task::noop_waker } ,
+	std       :: { task::{ Context, Poll }                                                     } ,
+};
+
+
+// The registry is private to the crate, so we compile it in here. These are the items it uses from the crate.
+//
+#[ allow( dead_code ) ]
+//
+#[ path = "../src/registry.rs" ]
+//
+mod registry;
+
+use async_nursery::NurseErr;
+
+mod import
+{
+	pub(crate) use std::sync::Arc;
+}
+
+
+const SIZES  : [usize; 3] = [ 10, 100, 1000 ];
+const THREADS: usize      = 4;
+
+
+
+async fn registry_all( amount: usize ) -> usize
+{
+	let (tx, mut rx) = registry::registry::<usize>();
+
+	for i in 0..amount
+	{
+		tx.send( i ).expect( "send" );
+	}
+
+	drop( tx );
+
+	let mut sum = 0;
+
+	while let Some(i) = poll_fn( |cx| rx.poll_recv( cx ) ).await
+	{
+		sum += i;
+	}
+
+	sum
+}
+
+
+
+async fn channel_all( amount: usize ) -> usize
+{
+	let (tx, mut rx) = mpsc::unbounded::<usize>();
+
+	for i in 0..amount
+	{
+		tx.unbounded_send( i ).expect( "send" );
+	}
+
+	drop( tx );
+
+	let mut sum = 0;
+
+	while let Some(i) = rx.next().await
+	{
+		sum += i;
+	}
+
+	sum
+}
+
+
+
+fn registry_interleaved( amount: usize ) -> usize
+{
+	let (tx, mut rx) = registry::registry::<usize>();
+	let waker        = noop_waker();
+	let mut cx       = Context::from_waker( &waker );
+	let mut sum      = 0;
+
+	for i in 0..amount
+	{
+		tx.send( i ).expect( "send" );
+
+		if let Poll::Ready( Some(i) ) = rx.poll_recv( &mut cx )
+		{
+			sum += i;
+		}
+	}
+
+	sum
+}
+
+
+
+fn channel_interleaved( amount: usize ) -> usize
+{
+	let (tx, mut rx) = mpsc::unbounded::<usize>();
+	let waker        = noop_waker();
+	let mut cx       = Context::from_waker( &waker );
+	let mut sum      = 0;
+
+	for i in 0..amount
+	{
+		tx.unbounded_send( i ).expect( "send" );
+
+		if let Poll::Ready( Some(i) ) = rx.poll_next_unpin( &mut cx )
+		{
+			sum += i;
+		}
+	}
+
+	sum
+}
+
+
+
+fn registry_threads( amount: usize ) -> usize
+{
+	let (tx, mut rx) = registry::registry::<usize>();
+
+	let senders: Vec<_> = (0..THREADS).map( |_|
+	{
+		let tx = tx.clone();
+
+		std::thread::spawn( move || for i in 0..amount { tx.send( i ).expect( "send" ); } )
+	})
+	.collect();
+
+	drop( tx );
+
+	let sum = block_on( async
+	{
+		let mut sum = 0;
+
+		while let Some(i) = poll_fn( |cx| rx.poll_recv( cx ) ).await
+		{
+			sum += i;
+		}
+
+		sum
+	});
+
+	senders.into_iter().for_each( |s| s.join().expect( "join" ) );
+
+	sum
+}
+
+
+
+fn channel_threads( amount: usize ) -> usize
+{
+	let (tx, mut rx) = mpsc::unbounded::<usize>();
+
+	let senders: Vec<_> = (0..THREADS).map( |_|
+	{
+		let tx = tx.clone();
+
+		std::thread::spawn( move || for i in 0..amount { tx.unbounded_send( i ).expect( "send" ); } )
+	})
+	.collect();
+
+	drop( tx );
+
+	let sum = block_on( async
+	{
+		let mut sum = 0;
+
+		while let Some(i) = rx.next().await
+		{
+			sum += i;
+		}
+
+		sum
+	});
+
+	senders.into_iter().for_each( |s| s.join().expect( "join" ) );
+
+	sum
+}
+
+
+
+fn registry( c: &mut Criterion )
+{
+	let mut group = c.benchmark_group( "send_all" );
+
+	for size in SIZES
+	{
+		group.bench_with_input( BenchmarkId::new( "registry", size ), &size, |b, &size| b.iter( || block_on( registry_all( size ) ) ) );
+		group.bench_with_input( BenchmarkId::new( "channel" , size ), &size, |b, &size| b.iter( || block_on( channel_all ( size ) ) ) );
+	}
+
+	group.finish();
+
+
+	let mut group = c.benchmark_group( "interleaved" );
+
+	for size in SIZES
+	{
+		group.bench_with_input( BenchmarkId::new( "registry", size ), &size, |b, &size| b.iter( || registry_interleaved( size ) ) );
+		group.bench_with_input( BenchmarkId::new( "channel" , size ), &size, |b, &size| b.iter( || channel_interleaved ( size ) ) );
+	}
+
+	group.finish();
+
+
+	let mut group = c.benchmark_group( "threads" );
+
+	for size in SIZES
+	{
+		group.bench_with_input( BenchmarkId::new( "registry", size ), &size, |b, &size| b.iter( || registry_threads( size ) ) );
+		group.bench_with_input( BenchmarkId::new( "channel" , size ), &size, |b, &size| b.iter( || channel_threads ( size ) ) );
+	}
+
+	group.finish();
+}
+
+
+
+criterion_group!( benches, registry );
+criterion_main! ( benches           );
@@ -1,8 +1,8 @@
 //! The point of cooperative cancellation is to not be potentially canceled at each await point
 //! but to be able to do cleanup.
 //!
-//! This is but one possible implementation of cooperative cancellation. This just uses an AtomicBool to indicate
-//! that tasks should stop.
+//! This uses the `CancelToken` that comes with every nursery. Tasks check it between units of work and
+//! `NurseryStream::cancel` trips it, after which we keep awaiting the stream so tasks can do their cleanup.
 //!
 //! Async drop should also provide a way to do cleanup when canceled, but doesn't exist yet.
 //!
@@ -31,17 +31,17 @@ mod common;
 
 use
 {
-	async_executors :: { AsyncStd                                                               } ,
-	async_nursery   :: { Nursery, Nurse, NurseExt                                               } ,
-	common          :: { DynResult, setup_tracing                                               } ,
-	futures_timer   :: { Delay                                                                  } ,
-	std             :: { time::Duration, sync::{ Arc, atomic::{ AtomicBool, Ordering::SeqCst }} } ,
-	tracing_crate   :: { info                                                                   } ,
+	async_executors :: { AsyncStd                              } ,
+	async_nursery   :: { Nursery, Nurse, NurseExt, CancelToken } ,
+	common          :: { DynResult, setup_tracing              } ,
+	futures_timer   :: { Delay                                 } ,
+	std             :: { time::Duration                        } ,
+	tracing_crate   :: { info                                  } ,
 };
 
 
 
-fn cancel_coop( amount: usize, nursery: impl Nurse<()>, cancel: Arc<AtomicBool>  ) -> DynResult<()>
+fn cancel_coop( amount: usize, nursery: impl Nurse<()>, cancel: CancelToken ) -> DynResult<()>
 {
 	for i in 1..=amount
 	{
@@ -61,7 +61,7 @@ fn cancel_coop( amount: usize, nursery: impl Nurse<()>, cancel: Arc<AtomicBool>
 // The function below doesn't do any cleanup. In practice, if you are fine with potentially
 // being dropped at an await point, you don't need cooperative cancellation.
 //
-async fn slow( i: usize, cancel: Arc<AtomicBool> )
+async fn slow( i: usize, cancel: CancelToken )
 {
 	info!( "spawned slow: {}", i );
 
@@ -70,7 +70,7 @@ async fn slow( i: usize, cancel: Arc<AtomicBool> )
 	//
 	for _ in 0..i
 	{
-		if cancel.load( SeqCst )
+		if cancel.is_cancelled()
 		{
 			info!( "slow {} doing cleanup", i );
 			return;
@@ -90,26 +90,22 @@ async fn main() -> DynResult<()>
 {
 	setup_tracing();
 
-	let (nursery, output) = Nursery::new( AsyncStd ); info!( "nursery created" );
+	let (nursery, mut output) = Nursery::new( AsyncStd ); info!( "nursery created" );
 
 	// cancel_coop will be able to spawn tasks that outlive it's own lifetime,
 	// and if its async, we can just spawn it on the nursery as well.
 	//
-	let cancel = Arc::new( AtomicBool::new( false ) );
-	cancel_coop( 5, nursery.clone(), cancel.clone() )?;
+	cancel_coop( 5, nursery.clone(), nursery.cancel_token() )?;
 
 	// cancel after 2 seconds.
 	//
 	Delay::new( Duration::from_secs(2) ).await;
 
-	info!( "canceling" );
-	cancel.store( true, SeqCst );
-
-	// This is necessary. Since we could keep spawning tasks even after starting to poll
-	// the output, it can't know that we are done, unless we drop all senders or call
-	// `close_nursery`.
+	// This trips the token and closes the nursery, so we don't need to drop the nursery
+	// for the await below to finish.
 	//
-	drop(nursery);
+	info!( "canceling" );
+	output.cancel();
 
 	// main will wait for subtasks to do cleanup. Of course you could select this
 	// with a timeout.
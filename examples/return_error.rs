@@ -1,5 +1,5 @@
-//! We can leverage TryStreamExt in order to cancel concurrent tasks early if one
-//! runs into an error.
+//! With `Nursery::new_try` we get a `TryNurseryStream` that cancels concurrent tasks early
+//! if one runs into an error.
 //!
 //! Expected output:
 //!
@@ -16,7 +16,6 @@ use
 	async_executors :: { AsyncStd                     } ,
 	async_nursery   :: { Nursery, NurseExt            } ,
 	common          :: { DynSendResult, setup_tracing } ,
-	futures         :: { TryStreamExt                 } ,
 	futures_timer   :: { Delay                        } ,
 	std             :: { time::Duration               } ,
 	tracing_crate   :: { info, error                  } ,
@@ -25,7 +24,7 @@ use
 
 async fn return_error() -> DynSendResult<()>
 {
-	let (nursery, mut output) = Nursery::new( AsyncStd ); info!( "nursery created" );
+	let (nursery, output) = Nursery::new_try( AsyncStd ); info!( "nursery created" );
 
 	nursery.nurse( slow()  )?;
 	nursery.nurse( wrong() )?;
@@ -35,21 +34,17 @@ async fn return_error() -> DynSendResult<()>
 	// `close_nursery`.
 	//
 	// Of course if an error happens, it wouldn't matter, but if no error happens it would
-	// make the while loop below hang indefinitely.
+	// make the await below hang indefinitely.
 	//
 	drop(nursery);
 
-	// Can't do it with TryFuture because we would need specialization.
-	// Use TryStreamExt. This will return as soon as an error happens in
-	// any spawned task and thus drop all the others when the current function
-	// returns.
+	// This will return as soon as an error happens in any spawned task and drop all
+	// the others. If all tasks succeed, we get a Vec with their outputs.
 	//
-	// Warning, that last phrase is important. It cancels all tasks when it get's
-	// dropped. Not when try_next stops. I will in this example because we use
-	// the `?` operator to return early and when the function returns the NurseryStream
-	// get's dropped.
+	// You can also poll it as a Stream, eg. with `TryStreamExt::try_next`. In that case
+	// the other tasks get dropped as soon as the stream yields an error.
 	//
-	while output.try_next().await?.is_some() {};
+	output.await?;
 
 	unreachable!( "drop Nursery and NurseryStream" );
 }
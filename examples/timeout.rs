@@ -1,10 +1,10 @@
-//! With an executor from async_executors you can use the timeout method to limit the life
-//! time of spawned tasks. Tasks that time out are dropped and no result is sent to the
-//! output stream.
+//! Use `nurse_timeout` to limit the life time of spawned tasks. Tasks that time out are dropped
+//! and yield `NurseErr::Timeout` to the output stream. To put a deadline on all tasks of a nursery,
+//! use `Nursery::with_deadline`.
 //!
-//! Expected output in 3 seconds:
+//! Expected output in 2.5 seconds:
 //!
-//! $ cargo run --example resource_await
+//! $ cargo run --example timeout
 //!
 //! INFO timeout: nursery created
 //! INFO timeout: spawned slow 0
@@ -15,14 +15,16 @@
 //! INFO timeout: completed slow 0
 //! INFO timeout: completed slow 1
 //! INFO timeout: completed slow 2
+//! INFO timeout: 2 tasks timed out
 //! INFO timeout: drop Nursery and NurseryStream
 //!
 mod common;
 
 use
 {
-	async_executors :: { AsyncStd, TimerExt           } ,
-	async_nursery   :: { Nursery, NurseExt            } ,
+	async_executors :: { AsyncStd                     } ,
+	async_nursery   :: { Nursery                      } ,
+	futures         :: { StreamExt                    } ,
 	common          :: { DynSendResult, setup_tracing } ,
 	futures_timer   :: { Delay                        } ,
 	std             :: { time::Duration               } ,
@@ -34,12 +36,11 @@ use
 async fn resource_await( amount: usize ) -> DynSendResult<()>
 {
 	let (nursery, output) = Nursery::new( AsyncStd ); info!( "nursery created" );
-	let delay = Duration::from_secs(2);
+	let delay = Duration::from_millis(2500);
 
 	for i in 0..amount
 	{
-		let task = nursery.timeout( delay, slow(i) );
-		nursery.nurse( task )?;
+		nursery.nurse_timeout( delay, slow(i) )?;
 	}
 
 	// This is necessary. Since we could keep spawning tasks even after starting to poll
@@ -50,7 +51,9 @@ async fn resource_await( amount: usize ) -> DynSendResult<()>
 
 	// Resolves when all spawned tasks are done.
 	//
-	output.await;
+	let timed_out = output.filter( |out| futures::future::ready( out.is_err() ) ).count().await;
+
+	info!( "{} tasks timed out", timed_out );
 
 	info!( "drop Nursery and NurseryStream" );
 	Ok(())
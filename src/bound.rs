@@ -0,0 +1,144 @@
+use crate:: { import::*, waker_set::WakerSet };
+
+
+/// Limits the number of tasks in flight on a bounded [`Nursery`](crate::Nursery).
+/// Shared by all clones of the nursery.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Bound
+{
+	max      : usize       ,
+	in_flight: AtomicUsize ,
+	waiters  : WakerSet    ,
+}
+
+
+
+impl Bound
+{
+	pub(crate) fn new( max: usize ) -> Arc<Self>
+	{
+		Arc::new( Self
+		{
+			max                           ,
+			in_flight: AtomicUsize::new(0) ,
+			waiters  : WakerSet::default() ,
+		})
+	}
+
+
+	/// Take a slot if one is available.
+	//
+	pub(crate) fn try_acquire( self: &Arc<Self> ) -> Option<Permit>
+	{
+		self.in_flight.fetch_update( Ordering::SeqCst, Ordering::SeqCst, |n|
+		{
+			if n < self.max { Some( n + 1 ) } else { None }
+		})
+		.ok()
+		.map( |_| Permit{ bound: self.clone() } )
+	}
+
+
+	/// Wait for a slot to become available. `key` identifies the waiter, so it must be
+	/// the same between polls.
+	//
+	pub(crate) fn poll_acquire( self: &Arc<Self>, cx: &mut Context<'_>, key: &mut Option<usize> ) -> Poll<Permit>
+	{
+		if let Some(permit) = self.try_acquire()
+		{
+			self.waiters.remove( key );
+			return Poll::Ready( permit )
+		}
+
+		self.waiters.register( key, cx.waker() );
+
+		// Check again after registering, so we can't miss a permit being released.
+		//
+		match self.try_acquire()
+		{
+			Some(permit) =>
+			{
+				self.waiters.remove( key );
+				Poll::Ready( permit )
+			}
+
+			None => Poll::Pending,
+		}
+	}
+
+
+	/// Stop waiting for a slot.
+	//
+	pub(crate) fn cancel_acquire( &self, key: &mut Option<usize> )
+	{
+		self.waiters.remove( key );
+	}
+
+
+	/// A future that resolves once a slot is available.
+	//
+	pub(crate) fn acquire( self: &Arc<Self> ) -> Acquire
+	{
+		Acquire{ bound: self.clone(), key: None }
+	}
+}
+
+
+
+/// Future returned by [`Bound::acquire`].
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Acquire
+{
+	bound: Arc<Bound>    ,
+	key  : Option<usize> ,
+}
+
+
+
+impl Future for Acquire
+{
+	type Output = Permit;
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Permit>
+	{
+		let this = &mut *self;
+
+		this.bound.poll_acquire( cx, &mut this.key )
+	}
+}
+
+
+
+impl Drop for Acquire
+{
+	fn drop( &mut self )
+	{
+		self.bound.cancel_acquire( &mut self.key );
+	}
+}
+
+
+
+/// A slot on a bounded nursery. The slot is released when this is dropped.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Permit
+{
+	bound: Arc<Bound>,
+}
+
+
+
+impl Drop for Permit
+{
+	fn drop( &mut self )
+	{
+		self.bound.in_flight.fetch_sub( 1, Ordering::SeqCst );
+		self.bound.waiters.wake_all();
+	}
+}
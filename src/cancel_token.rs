@@ -0,0 +1,169 @@
+use crate:: { import::*, waker_set::WakerSet };
+
+
+/// A token for cooperative cancellation. Every [`Nursery`](crate::Nursery) comes with one,
+/// shared by all of it's clones and by the [`NurseryStream`](crate::NurseryStream).
+///
+/// Tasks that need to do cleanup before stopping can obtain a token with
+/// [`Nursery::cancel_token`](crate::Nursery::cancel_token) and either check
+/// [`is_cancelled`](CancelToken::is_cancelled) between units of work or await
+/// [`cancelled`](CancelToken::cancelled), eg. in a `select`.
+///
+/// The token is tripped by [`NurseryStream::cancel`](crate::NurseryStream::cancel) or by calling
+/// [`CancelToken::cancel`] directly. Once cancelled, a token stays cancelled.
+///
+/// A token can have [children](CancelToken::child_token). Cancelling a token cancels all of it's
+/// descendants, but cancelling a child does not affect it's parent.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Clone, Debug, Default ) ]
+//
+pub struct CancelToken
+{
+	inner: Arc<Inner>,
+}
+
+
+#[ derive( Debug, Default ) ]
+//
+struct Inner
+{
+	cancelled: AtomicBool                ,
+	wakers   : WakerSet                  ,
+	children : Mutex< Vec<Weak<Inner>> > ,
+}
+
+
+
+impl CancelToken
+{
+	/// Create a new token that is not cancelled.
+	//
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+
+	/// Request cancellation. Wakes up all tasks awaiting [`cancelled`](CancelToken::cancelled).
+	//
+	pub fn cancel( &self )
+	{
+		self.inner.cancel();
+	}
+
+
+	/// Create a token that is cancelled when this one is. Cancelling the child does not cancel
+	/// this token. If this token is already cancelled, so is the child.
+	//
+	pub fn child_token( &self ) -> Self
+	{
+		let child        = Self::new();
+		let mut children = self.inner.children.lock().unwrap_or_else( PoisonError::into_inner );
+
+		// cancel sets the flag before taking the lock, so if it isn't set yet, cancel will see the child.
+		//
+		if self.is_cancelled()
+		{
+			child.cancel();
+		}
+
+		else
+		{
+			children.retain( |c| c.strong_count() > 0 );
+			children.push( Arc::downgrade( &child.inner ) );
+		}
+
+		drop( children );
+
+		child
+	}
+
+
+	/// Whether cancellation has been requested.
+	//
+	pub fn is_cancelled( &self ) -> bool
+	{
+		self.inner.cancelled.load( Ordering::SeqCst )
+	}
+
+
+	/// A future that resolves once cancellation has been requested.
+	//
+	pub fn cancelled( &self ) -> Cancelled
+	{
+		Cancelled{ token: self.clone(), key: None }
+	}
+}
+
+
+
+impl Inner
+{
+	fn cancel( &self )
+	{
+		if self.cancelled.swap( true, Ordering::SeqCst ) { return }
+
+		self.wakers.wake_all();
+
+		let children = std::mem::take( &mut *self.children.lock().unwrap_or_else( PoisonError::into_inner ) );
+
+		for child in children.iter().filter_map( Weak::upgrade )
+		{
+			child.cancel();
+		}
+	}
+}
+
+
+
+/// Future returned by [`CancelToken::cancelled`]. Resolves once the token is cancelled.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+#[ must_use = "futures do nothing unless polled" ]
+//
+pub struct Cancelled
+{
+	token: CancelToken   ,
+	key  : Option<usize> ,
+}
+
+
+
+impl Future for Cancelled
+{
+	type Output = ();
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		if self.token.is_cancelled() { return Poll::Ready(()) }
+
+		let this = &mut *self;
+
+		this.token.inner.wakers.register( &mut this.key, cx.waker() );
+
+		// Check again after registering, so we can't miss a call to cancel.
+		//
+		if this.token.is_cancelled()
+		{
+			this.token.inner.wakers.remove( &mut this.key );
+			return Poll::Ready(())
+		}
+
+		Poll::Pending
+	}
+}
+
+
+
+impl Drop for Cancelled
+{
+	fn drop( &mut self )
+	{
+		self.token.inner.wakers.remove( &mut self.key );
+	}
+}
@@ -0,0 +1,247 @@
+use crate           :: { import::*, Nurse, LocalNurse, NurseErr, registry::{ registry, Sender, Receiver } };
+use core            :: { pin::Pin, task::{ Context, Poll }                                                 };
+use futures_channel :: { oneshot                                                                          };
+use futures_task    :: { Spawn, LocalSpawn                                                                 };
+use futures_util    :: { future::poll_fn, stream::{ Stream, FusedStream, FuturesUnordered, StreamExt }     };
+
+
+/// A nursery that only needs `alloc`. It spawns on any executor that implements [`Spawn`] or
+/// [`LocalSpawn`], so it works without `std` and without _async_executors_.
+///
+/// It's a lot simpler than [`Nursery`](crate::Nursery). There is no [`CancelToken`](crate::CancelToken),
+/// no bound, no deadlines and no panic policy. Each task hands it's output to the [`CoreNurseryStream`]
+/// over a oneshot channel. When the stream is dropped, the tasks notice and drop the future they are
+/// running the next time they are woken up or polled.
+///
+/// A task that panics or that the executor drops doesn't yield an output. The panic is handled by the
+/// executor.
+///
+/// Will disconnect on drop. You can close all senders by calling `close_nursery`.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "core_nursery" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct CoreNursery<S, Out>
+{
+	spawner: S                                ,
+	tx     : Sender< oneshot::Receiver<Out> > ,
+}
+
+
+impl<S, Out> Clone for CoreNursery<S, Out> where S: Clone
+{
+	fn clone( &self ) -> Self
+	{
+		Self
+		{
+			spawner: self.spawner.clone(),
+			tx     : self.tx     .clone(),
+		}
+	}
+}
+
+
+
+impl<S, Out> CoreNursery<S, Out>
+{
+	/// Create a new nursery. Returns a tuple of the sender part
+	/// and the stream of outputs.
+	///
+	pub fn new( spawner: S ) -> (Self, CoreNurseryStream<Out>)
+	{
+		let (tx, rx) = registry();
+
+		( Self{ spawner, tx }, CoreNurseryStream::new( rx ) )
+	}
+
+
+	/// Stop this nursery and any clones from accepting any more tasks. Calling this or
+	/// dropping all `CoreNursery` is necessary for the stream impl of `CoreNurseryStream` to end
+	/// and return `None`.
+	//
+	pub fn close_nursery( &self )
+	{
+		self.tx.close();
+	}
+}
+
+
+
+/// Wrap the future so it sends it's output to the stream, or stops when the stream is gone.
+//
+fn task<F, Out>( fut: F ) -> ( impl Future<Output = ()>, oneshot::Receiver<Out> )
+
+	where F: Future<Output = Out> + Unpin
+{
+	let (mut tx, rx) = oneshot::channel();
+
+	let task = async move
+	{
+		let mut fut = fut;
+
+		let out = poll_fn( |cx|
+		{
+			if tx.poll_canceled( cx ).is_ready()
+			{
+				return Poll::Ready( None )
+			}
+
+			Pin::new( &mut fut ).poll( cx ).map( Some )
+
+		}).await;
+
+		if let Some(out) = out
+		{
+			// The stream might be gone by now, in which case nobody wants the output.
+			//
+			let _ = tx.send( out );
+		}
+	};
+
+	( task, rx )
+}
+
+
+
+impl<S, Out> Nurse<Out> for CoreNursery<S, Out> where S: Spawn, Out: 'static + Send
+{
+	fn nurse_obj( &self, fut: FutureObj<'static, Out> ) -> Result<(), NurseErr>
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let (task, rx) = task( fut );
+
+		self.spawner.spawn_obj( FutureObj::new( Box::new(task) ) )?;
+
+		// If the stream is gone, dropping rx will make the task stop.
+		//
+		self.tx.send( rx )
+	}
+}
+
+
+
+impl<S, Out> LocalNurse<Out> for CoreNursery<S, Out> where S: LocalSpawn, Out: 'static
+{
+	fn nurse_local_obj( &self, fut: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let (task, rx) = task( fut );
+
+		self.spawner.spawn_local_obj( LocalFutureObj::new( Box::new(task) ) )?;
+
+		// If the stream is gone, dropping rx will make the task stop.
+		//
+		self.tx.send( rx )
+	}
+}
+
+
+
+/// The outputs of the tasks spawned on a [`CoreNursery`], in the order they finish. Await the
+/// [`Future`] implementation if you just want to wait until all tasks are done.
+///
+/// The stream ends once the `CoreNursery` and all it's clones are dropped or closed and all tasks
+/// have finished. Dropping it cancels the tasks.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "core_nursery" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct CoreNurseryStream<Out>
+{
+	rx       : Receiver< oneshot::Receiver<Out> >          ,
+	unordered: FuturesUnordered< oneshot::Receiver<Out> >  ,
+	rx_closed: bool                                        ,
+}
+
+
+
+impl<Out> CoreNurseryStream<Out>
+{
+	fn new( rx: Receiver< oneshot::Receiver<Out> > ) -> Self
+	{
+		Self
+		{
+			rx                                ,
+			unordered: FuturesUnordered::new(),
+			rx_closed: false                  ,
+		}
+	}
+
+
+	/// Stop the [`CoreNursery`] and all it's clones from accepting new tasks. The tasks already
+	/// spawned keep running and the stream ends once they are done.
+	//
+	pub fn close_nursery( &self )
+	{
+		self.rx.close();
+	}
+}
+
+
+
+impl<Out> Stream for CoreNurseryStream<Out>
+{
+	type Item = Out;
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		let this = &mut *self;
+
+		while !this.rx_closed
+		{
+			match this.rx.poll_recv( cx )
+			{
+				Poll::Ready( Some(task) ) => this.unordered.push( task ),
+				Poll::Ready( None       ) => this.rx_closed = true      ,
+				Poll::Pending             => break                      ,
+			}
+		}
+
+		loop
+		{
+			match this.unordered.poll_next_unpin( cx )
+			{
+				Poll::Ready( Some( Ok(out) ) ) => return Poll::Ready( Some(out) ),
+
+				// The task panicked or the executor dropped it.
+				//
+				Poll::Ready( Some( Err(_) ) ) => continue,
+
+				Poll::Ready( None ) if this.rx_closed => return Poll::Ready( None ),
+
+				// Either tasks are running or the nursery can still send new ones. In both
+				// cases we have registered our waker.
+				//
+				Poll::Ready( None ) | Poll::Pending => return Poll::Pending,
+			}
+		}
+	}
+}
+
+
+
+impl<Out> FusedStream for CoreNurseryStream<Out>
+{
+	fn is_terminated( &self ) -> bool
+	{
+		self.rx_closed && self.unordered.is_empty()
+	}
+}
+
+
+
+impl<Out> Future for CoreNurseryStream<Out>
+{
+	type Output = ();
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		while let Some(_out) = futures_util::ready!( self.as_mut().poll_next( cx ) ) {}
+
+		Poll::Ready(())
+	}
+}
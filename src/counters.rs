@@ -0,0 +1,223 @@
+use crate:: { import::*, entry::Exit };
+
+#[ cfg( feature = "metrics" ) ]
+//
+use crate::observer::{ Observed, TaskEnd };
+
+
+/// Counts the tasks of a nursery. Shared by all clones of the [`Nursery`](crate::Nursery) and the
+/// [`NurseryStream`](crate::NurseryStream). Every task is also counted by the counters of the
+/// parents of a [child nursery](crate::Nursery::child).
+//
+#[ derive( Debug, Default ) ]
+//
+pub(crate) struct Counters
+{
+	parent   : Option< Arc<Counters> > ,
+	spawned  : AtomicU64               ,
+	completed: AtomicU64               ,
+	active   : AtomicUsize             ,
+	queued   : AtomicUsize             ,
+}
+
+
+
+impl Counters
+{
+	/// Create the counters of a child nursery.
+	//
+	pub(crate) fn child( self: &Arc<Self> ) -> Arc<Self>
+	{
+		Arc::new( Self{ parent: Some( self.clone() ), ..Self::default() } )
+	}
+
+
+	/// Start tracking a task that is about to be sent to the `NurseryStream`.
+	//
+	pub(crate) fn track( self: &Arc<Self> ) -> Tracker
+	{
+		self.each( |c|
+		{
+			c.active.fetch_add( 1, Ordering::Relaxed );
+			c.queued.fetch_add( 1, Ordering::Relaxed );
+		});
+
+		Tracker
+		{
+			counters: self.clone(),
+			queued  : true        ,
+
+			#[ cfg( feature = "metrics" ) ]
+			//
+			observed: None        ,
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span    : None        ,
+		}
+	}
+
+
+	/// Count a task that was successfully handed to the `NurseryStream`.
+	//
+	pub(crate) fn spawned( &self )
+	{
+		self.each( |c| { c.spawned.fetch_add( 1, Ordering::Relaxed ); } );
+	}
+
+
+	pub(crate) fn active_tasks( &self ) -> usize
+	{
+		self.active.load( Ordering::Relaxed )
+	}
+
+
+	pub(crate) fn queued_handles( &self ) -> usize
+	{
+		self.queued.load( Ordering::Relaxed )
+	}
+
+
+	pub(crate) fn completed_total( &self ) -> u64
+	{
+		self.completed.load( Ordering::Relaxed )
+	}
+
+
+	pub(crate) fn spawned_total( &self ) -> u64
+	{
+		self.spawned.load( Ordering::Relaxed )
+	}
+
+
+	/// Apply `f` to these counters and those of all ancestors.
+	//
+	fn each( &self, f: impl Fn( &Self ) )
+	{
+		let mut next = Some( self );
+
+		while let Some(counters) = next
+		{
+			f( counters );
+			next = counters.parent.as_deref();
+		}
+	}
+}
+
+
+
+/// Held by a task for as long as the `NurseryStream` is responsible for it. When it is dropped,
+/// the task is no longer active, whether it finished or was dropped.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Tracker
+{
+	counters: Arc<Counters> ,
+	queued  : bool          ,
+
+	#[ cfg( feature = "metrics" ) ]
+	//
+	observed: Option<Observed> ,
+
+	#[ cfg( feature = "tracing" ) ]
+	//
+	span    : Option<Span>     ,
+}
+
+
+
+impl Tracker
+{
+	/// The `NurseryStream` took the task out of the registry.
+	//
+	pub(crate) fn dequeue( &mut self )
+	{
+		if std::mem::take( &mut self.queued )
+		{
+			self.counters.each( |c| { c.queued.fetch_sub( 1, Ordering::Relaxed ); } );
+		}
+	}
+
+
+	/// Report the end of the task to an observer.
+	//
+	#[ cfg( feature = "metrics" ) ]
+	//
+	pub(crate) fn observed( mut self, observed: Option<Observed> ) -> Self
+	{
+		self.observed = observed;
+		self
+	}
+
+
+	/// Emit events for the task in it's span.
+	//
+	#[ cfg( feature = "tracing" ) ]
+	//
+	pub(crate) fn in_span( mut self, span: Span ) -> Self
+	{
+		tracing_crate::debug!( parent: &span, "task spawned" );
+
+		self.span = Some( span );
+		self
+	}
+
+
+	/// The task finished.
+	//
+	#[ cfg_attr( not(any( feature = "metrics", feature = "tracing" )), allow( unused_variables ) ) ]
+	//
+	pub(crate) fn complete<Out>( &mut self, exit: &Exit<Out> )
+	{
+		self.counters.each( |c| { c.completed.fetch_add( 1, Ordering::Relaxed ); } );
+
+		#[ cfg( feature = "metrics" ) ]
+		//
+		if let Some(observed) = self.observed.take()
+		{
+			observed.end( match exit
+			{
+				Exit::Panicked(..) => TaskEnd::Panicked  ,
+				_                  => TaskEnd::Completed ,
+			});
+		}
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		if let Some(span) = self.span.take()
+		{
+			match exit
+			{
+				Exit::Panicked(..) => tracing_crate::warn! ( parent: &span, "task panicked"   ),
+				Exit::TimedOut(..) => tracing_crate::debug!( parent: &span, "task timed out"  ),
+				_                  => tracing_crate::debug!( parent: &span, "task completed" ),
+			}
+		}
+	}
+}
+
+
+
+impl Drop for Tracker
+{
+	fn drop( &mut self )
+	{
+		self.dequeue();
+		self.counters.each( |c| { c.active.fetch_sub( 1, Ordering::Relaxed ); } );
+
+		#[ cfg( feature = "metrics" ) ]
+		//
+		if let Some(observed) = self.observed.take()
+		{
+			observed.end( TaskEnd::Dropped );
+		}
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		if let Some(span) = self.span.take()
+		{
+			tracing_crate::debug!( parent: &span, "task dropped before it finished" );
+		}
+	}
+}
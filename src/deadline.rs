@@ -0,0 +1,105 @@
+use crate:: { import::* };
+
+
+/// A point in time after which tasks are cancelled. The sleep is shared, so that all tasks of a nursery
+/// with a deadline wait on the same timer.
+//
+pub(crate) struct Deadline<Out>
+{
+	sleep  : Shared< BoxFuture<'static, ()> > ,
+	expired: fn() -> Out                      ,
+}
+
+
+
+impl<Out> Deadline<Out>
+{
+	/// `expired` creates the output for tasks that are cancelled because the deadline passed.
+	//
+	pub(crate) fn new( sleep: BoxFuture<'static, ()>, expired: fn() -> Out ) -> Self
+	{
+		let deadline = Self { sleep: sleep.shared(), expired };
+
+		// Some timers only start counting when first polled, so poll it right away.
+		//
+		deadline.has_passed();
+
+		deadline
+	}
+
+
+	/// Whether the deadline has passed.
+	//
+	pub(crate) fn has_passed( &self ) -> bool
+	{
+		self.sleep.clone().now_or_never().is_some()
+	}
+
+
+	/// Wrap a future so it resolves to the expired output if it doesn't finish before the deadline.
+	/// The future is dropped in that case and `timed_out` is set.
+	//
+	pub(crate) fn wrap<F>( &self, fut: F, timed_out: TimedOut ) -> impl Future<Output = Out>
+
+		where F: Future<Output = Out>
+	{
+		let sleep   = self.sleep.clone();
+		let expired = self.expired;
+
+		async move
+		{
+			futures::pin_mut!( fut );
+
+			match select( fut, sleep ).await
+			{
+				Either::Left ( (out, _) ) => out,
+				Either::Right( _        ) => { timed_out.set(); expired() }
+			}
+		}
+	}
+}
+
+
+
+/// Set when a deadline dropped a task, so the [`NurseryStream`](crate::NurseryStream) can tell the
+/// expired output from a regular one.
+//
+#[ derive( Clone, Default, Debug ) ]
+//
+pub(crate) struct TimedOut( Arc<AtomicBool> );
+
+
+
+impl TimedOut
+{
+	fn set( &self )
+	{
+		self.0.store( true, Ordering::Release );
+	}
+
+
+	pub(crate) fn is_set( &self ) -> bool
+	{
+		self.0.load( Ordering::Acquire )
+	}
+}
+
+
+
+impl<Out> Clone for Deadline<Out>
+{
+	fn clone( &self ) -> Self
+	{
+		Self { sleep: self.sleep.clone(), expired: self.expired }
+	}
+}
+
+
+
+impl<Out> std::fmt::Debug for Deadline<Out>
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		f.debug_struct( "Deadline" ).finish_non_exhaustive()
+	}
+}
@@ -0,0 +1,272 @@
+use crate:: { import::*, NurseryStream, CancelToken, TaskId, counters::Tracker, deadline::TimedOut, task_handle::{ TaskSlot, Side }, panic_policy::{ PanicSlot, OnPanic } };
+use std  :: { any::Any };
+
+
+/// What the [`Nursery`](crate::Nursery) sends to the [`NurseryStream`].
+//
+#[ derive( Debug ) ]
+//
+pub(crate) enum Message<Out>
+{
+	/// A task to manage.
+	//
+	Task( Entry<Out> ),
+
+	/// The stream of a child nursery, see [`Nursery::child`](crate::Nursery::child).
+	//
+	Child( Box< NurseryStream<Out> > ),
+}
+
+
+
+impl<Out> From< Entry<Out> > for Message<Out>
+{
+	fn from( entry: Entry<Out> ) -> Self
+	{
+		Message::Task( entry )
+	}
+}
+
+
+
+/// The name of a task, see [`TaskBuilder::name`](crate::TaskBuilder::name).
+//
+pub(crate) type Name = Option< Arc<str> >;
+
+
+
+/// A task as it is sent from the [`Nursery`](crate::Nursery) to the [`NurseryStream`].
+///
+/// Resolves to the id and name of the task and how it ended.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Entry<Out>
+{
+	id       : TaskId                 ,
+	task     : Task<Out>              ,
+	panic    : Option<Arc<PanicSlot>> ,
+	critical : bool                   ,
+	timed_out: Option<TimedOut>       ,
+	tracker  : Tracker                ,
+	name     : Name                   ,
+}
+
+
+#[ derive( Debug ) ]
+//
+enum Task<Out>
+{
+	/// A task of which the stream yields the output.
+	//
+	Handle( JoinHandle<Out> ),
+
+	/// A task of which the output goes to a [`TaskHandle`](crate::TaskHandle).
+	//
+	Slot( Arc<TaskSlot<Out>> ),
+
+	/// A closure running on a thread for blocking work, see [`Nursery::nurse_blocking`](crate::Nursery::nurse_blocking).
+	/// Panics are caught on the blocking thread. As blocking tasks can't be dropped, the token tells them to stop.
+	//
+	Blocking
+	{
+		handle  : BlockingHandle< std::thread::Result<Out> > ,
+		on_panic: OnPanic                                     ,
+		token   : CancelToken                                 ,
+	},
+}
+
+
+/// How a task ended, as seen by the `NurseryStream`.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) enum Exit<Out>
+{
+	/// The task returned an output for the stream.
+	//
+	Output( Out ),
+
+	/// A deadline dropped the task, which yields the expired output instead.
+	//
+	TimedOut( Out ),
+
+	/// The `NurseryStream` dropped the task before it finished.
+	//
+	Cancelled,
+
+	/// The task finished, but it's output went to a [`TaskHandle`](crate::TaskHandle).
+	//
+	Detached,
+
+	/// The task panicked and the panic was caught according to the [`PanicPolicy`](crate::PanicPolicy).
+	//
+	Panicked( Payload, OnPanic ),
+}
+
+
+
+/// The payload of a panic. It's behind a mutex so the types that hold on to it stay `Sync`, as the
+/// payload itself is only `Send`.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Payload( Mutex< Box<dyn Any + Send> > );
+
+
+
+impl Payload
+{
+	pub(crate) fn new( payload: Box<dyn Any + Send> ) -> Self
+	{
+		Self( Mutex::new( payload ) )
+	}
+
+
+	pub(crate) fn into_inner( self ) -> Box<dyn Any + Send>
+	{
+		self.0.into_inner().unwrap_or_else( PoisonError::into_inner )
+	}
+}
+
+
+
+impl<Out> Entry<Out>
+{
+	pub(crate) fn handle( id: TaskId, handle: JoinHandle<Out>, panic: Option<Arc<PanicSlot>>, critical: bool, timed_out: Option<TimedOut>, tracker: Tracker ) -> Self
+	{
+		Self{ id, task: Task::Handle(handle), panic, critical, timed_out, tracker, name: None }
+	}
+
+
+	pub(crate) fn slot( id: TaskId, slot: Arc<TaskSlot<Out>>, panic: Option<Arc<PanicSlot>>, critical: bool, tracker: Tracker ) -> Self
+	{
+		Self{ id, task: Task::Slot(slot), panic, critical, timed_out: None, tracker, name: None }
+	}
+
+
+	pub(crate) fn named( mut self, name: Name ) -> Self
+	{
+		self.name = name;
+		self
+	}
+
+
+	pub(crate) fn id( &self ) -> TaskId
+	{
+		self.id
+	}
+
+
+	/// The id and name of the task.
+	//
+	pub(crate) fn label( &self ) -> (TaskId, Name)
+	{
+		( self.id, self.name.clone() )
+	}
+
+
+	/// Whether the `NurseryStream` waits for this task. See [`TaskBuilder::critical`](crate::TaskBuilder::critical).
+	//
+	pub(crate) fn is_critical( &self ) -> bool
+	{
+		self.critical
+	}
+
+
+	/// Whether the task can give an output to the `NurseryStream`. Background tasks are dropped once
+	/// only they are left and tasks with a [`TaskHandle`](crate::TaskHandle) deliver to the handle.
+	//
+	pub(crate) fn yields_output( &self ) -> bool
+	{
+		self.critical && !matches!( self.task, Task::Slot(_) )
+	}
+
+
+	pub(crate) fn blocking( id: TaskId, handle: BlockingHandle< std::thread::Result<Out> >, on_panic: OnPanic, token: CancelToken, critical: bool, tracker: Tracker ) -> Self
+	{
+		Self{ id, task: Task::Blocking{ handle, on_panic, token }, panic: None, critical, timed_out: None, tracker, name: None }
+	}
+
+
+	/// The `NurseryStream` took this entry out of the registry.
+	//
+	pub(crate) fn dequeue( &mut self )
+	{
+		self.tracker.dequeue();
+	}
+}
+
+
+
+impl<Out> Future for Entry<Out>
+
+	where Out: 'static
+{
+	type Output = (TaskId, Name, Exit<Out>);
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		let this = self.get_mut();
+
+		let exit = match this.panic.as_ref().and_then( |p| p.poll_take(cx) )
+		{
+			Some( (payload, on_panic) ) => Exit::Panicked( Payload::new( payload ), on_panic ),
+
+			None => match &mut this.task
+			{
+				// Most executors resume the panic of a task when polling it's JoinHandle.
+				//
+				Task::Handle( handle ) => match std::panic::catch_unwind( AssertUnwindSafe( || Pin::new( handle ).poll( cx ) ) )
+				{
+					Err( payload )      => Exit::Panicked( Payload::new( payload ), OnPanic::Propagate ),
+					Ok ( Poll::Pending ) => return Poll::Pending,
+
+					Ok ( Poll::Ready(out) ) => match &this.timed_out
+					{
+						Some(t) if t.is_set() => Exit::TimedOut( out ),
+						_                     => Exit::Output  ( out ),
+					}
+				}
+
+				// The output goes to the TaskHandle, but a panic is reported here rather than resumed
+				// on the thread polling the NurseryStream.
+				//
+				Task::Slot( slot ) => match std::panic::catch_unwind( AssertUnwindSafe( || slot.poll_state( Side::Stream, cx ).map( drop ) ) )
+				{
+					Err( payload )        => Exit::Panicked( Payload::new( payload ), OnPanic::Propagate ),
+					Ok ( Poll::Pending  ) => return Poll::Pending,
+					Ok ( Poll::Ready(()) ) => Exit::Detached,
+				}
+
+				Task::Blocking{ handle, on_panic, .. } => match ready!( Pin::new( handle ).poll( cx ) )
+				{
+					Ok ( out     ) => Exit::Output  ( out              ),
+					Err( payload ) => Exit::Panicked( Payload::new( payload ), *on_panic ),
+				}
+			}
+		};
+
+		this.tracker.complete( &exit );
+
+		Poll::Ready( (this.id, this.name.take(), exit) )
+	}
+}
+
+
+
+impl<Out> Drop for Entry<Out>
+{
+	fn drop( &mut self )
+	{
+		// The NurseryStream is responsible for the task, so if we get dropped before it
+		// finished, it must be dropped as well. Blocking tasks can only be asked to stop.
+		//
+		match &self.task
+		{
+			Task::Slot    ( slot      ) => slot.abort(),
+			Task::Blocking{ token, .. } => token.cancel(),
+			Task::Handle  ( _         ) => {}
+		}
+	}
+}
@@ -14,16 +14,27 @@ pub enum NurseErr
 	/// The nursery is closed and no longer accepts new tasks.
 	//
 	Closed,
+
+	/// The nursery is bounded and has reached it's limit of tasks in flight.
+	//
+	Full,
+
+	/// The task did not finish before it's timeout or the deadline of the nursery.
+	/// Also returned when spawning on a nursery of which the deadline has passed.
+	//
+	Timeout,
 }
 
 
 
+#[ cfg( feature = "std" ) ]
+//
 impl std::error::Error for NurseErr {}
 
 
-impl std::fmt::Display for NurseErr
+impl core::fmt::Display for NurseErr
 {
-	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	fn fmt( &self, f: &mut core::fmt::Formatter<'_> ) -> core::fmt::Result
 	{
 		match &self
 		{
@@ -34,6 +45,14 @@ impl std::fmt::Display for NurseErr
 			NurseErr::Closed =>
 
 				write!( f, "The nursery is closed and no longer accepts new tasks." ),
+
+			NurseErr::Full =>
+
+				write!( f, "The nursery has reached it's limit of tasks in flight." ),
+
+			NurseErr::Timeout =>
+
+				write!( f, "The task did not finish before the deadline." ),
 		}
 	}
 }
@@ -51,6 +70,8 @@ impl From< SpawnError > for NurseErr
 
 
 
+#[ cfg( feature = "std" ) ]
+//
 impl<T> From< TrySendError<T> > for NurseErr
 {
 	fn from( _: TrySendError<T> ) -> NurseErr
@@ -61,9 +82,9 @@ impl<T> From< TrySendError<T> > for NurseErr
 
 
 
-impl From< NurseErr > for Box<dyn std::any::Any + Send>
+impl From< NurseErr > for Box<dyn core::any::Any + Send>
 {
-	fn from( err: NurseErr ) -> Box<dyn std::any::Any + Send>
+	fn from( err: NurseErr ) -> Box<dyn core::any::Any + Send>
 	{
 		Box::new( err )
 	}
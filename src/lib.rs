@@ -1,4 +1,5 @@
 #![ cfg_attr( nightly, feature(doc_cfg) ) ]
+#![ cfg_attr( not( feature = "std" ), no_std ) ]
 #![ doc = include_str!("../README.md") ]
 
 #![ doc    ( html_root_url = "https://docs.rs/async_nursery" ) ]
@@ -23,6 +24,8 @@
 )]
 
 
+extern crate alloc;
+
 mod error          ;
 mod nurse          ;
 mod local_nurse    ;
@@ -34,11 +37,31 @@ pub use
 	local_nurse :: * ,
 };
 
-#[ cfg( feature = "tracing"        ) ] mod tracing        ;
-#[ cfg( feature = "implementation" ) ] mod nursery        ;
-#[ cfg( feature = "implementation" ) ] mod nursery_stream ;
+#[ cfg( feature = "core_nursery"   ) ] mod core_nursery       ;
+#[ cfg( feature = "tracing"        ) ] mod tracing            ;
+#[ cfg( feature = "implementation" ) ] mod bound              ;
+#[ cfg( feature = "implementation" ) ] mod cancel_token       ;
+#[ cfg( feature = "implementation" ) ] mod counters           ;
+#[ cfg( feature = "implementation" ) ] mod deadline           ;
+#[ cfg( feature = "implementation" ) ] mod entry              ;
+#[ cfg( feature = "implementation" ) ] mod nursery            ;
+#[ cfg( feature = "implementation" ) ] mod nursery_errors     ;
+#[ cfg( feature = "implementation" ) ] mod nursery_stream     ;
+#[ cfg( feature = "metrics"        ) ] mod observer           ;
+#[ cfg( feature = "implementation" ) ] mod panic_policy       ;
+#[ cfg(any( feature = "implementation", feature = "core_nursery" )) ] mod registry ;
+#[ cfg( feature = "implementation" ) ] mod scope              ;
+#[ cfg( feature = "implementation" ) ] mod supervisor         ;
+#[ cfg( feature = "implementation" ) ] mod task_builder       ;
+#[ cfg( feature = "implementation" ) ] mod task_handle        ;
+#[ cfg( feature = "implementation" ) ] mod task_id            ;
+#[ cfg( feature = "implementation" ) ] mod task_outcome       ;
+#[ cfg( feature = "implementation" ) ] mod try_nursery_stream ;
+#[ cfg( feature = "implementation" ) ] mod waker_set          ;
 
-#[ cfg( feature = "implementation" ) ] pub use { nursery::*, nursery_stream::* };
+#[ cfg( feature = "implementation" ) ] pub use { cancel_token::*, nursery::*, nursery_errors::NurseryErrors, nursery_stream::*, panic_policy::PanicPolicy, scope::{ scope, Scope }, supervisor::{ Supervisor, RestartPolicy, Strategy, Failure }, task_builder::TaskBuilder, task_handle::TaskHandle, task_id::TaskId, task_outcome::{ TaskOutcome, Outcomes }, try_nursery_stream::* };
+#[ cfg( feature = "metrics"        ) ] pub use { observer::{ NurseryObserver, TaskEnd } };
+#[ cfg( feature = "core_nursery"   ) ] pub use { core_nursery::{ CoreNursery, CoreNurseryStream } };
 
 // External dependencies
 //
@@ -46,9 +69,17 @@ mod import
 {
 	pub(crate) use
 	{
-		futures_channel  :: { mpsc::TrySendError } ,
 		futures_task     :: { FutureObj, LocalFutureObj, SpawnError                                 } ,
-		std              :: { future::Future, sync::Arc, rc::Rc                                     } ,
+		alloc            :: { boxed::Box, sync::Arc, rc::Rc                                         } ,
+		core             :: { future::Future                                                        } ,
+	};
+
+
+	#[ cfg( feature = "std" ) ]
+	//
+	pub(crate) use
+	{
+		futures_channel  :: { mpsc::TrySendError } ,
 	};
 
 
@@ -57,10 +88,20 @@ mod import
 	pub(crate) use
 	{
 		async_executors  :: { SpawnHandle, LocalSpawnHandle, JoinHandle, Timer, TokioIo, SpawnBlocking, YieldNow, BlockingHandle, YieldNowFut } ,
-		futures          :: { ready, Stream, Sink, future::{ BoxFuture, FusedFuture }, stream::{ FusedStream, FuturesUnordered }              } ,
-		futures_channel  :: { mpsc::{ UnboundedSender, UnboundedReceiver, unbounded }                                                         } ,
+		futures          :: { ready, Stream, Sink, future::{ BoxFuture, FusedFuture, Shared, Either, poll_fn, pending, select }, stream::{ FusedStream, FuturesUnordered }, task::AtomicWaker } ,
+		futures          :: { FutureExt, StreamExt                                                                                            } ,
 		futures_task     :: { Spawn, LocalSpawn                                                                                               } ,
-		std              :: { task::{ Context, Poll }, pin::Pin, time::Duration                                                               } ,
+		std              :: { task::{ Context, Poll, Waker, Wake }, pin::Pin, time::Duration, collections::{ HashMap, HashSet, VecDeque }     } ,
+		std              :: { panic::AssertUnwindSafe                                                                                        } ,
+		std              :: { sync::{ Mutex, MutexGuard, PoisonError, Weak, atomic::{ AtomicBool, AtomicUsize, AtomicU64, Ordering } }                        } ,
+	};
+
+
+	#[ cfg(all( feature = "tracing", feature = "implementation" )) ]
+	//
+	pub(crate) use
+	{
+		tracing_crate    :: { Span, Instrument } ,
 	};
 }
 
@@ -25,6 +25,8 @@ impl<T, Out> LocalNurse<Out> for &T
 	where T  : LocalNurse<Out> + ?Sized ,
 	      Out: 'static                  ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_local_obj( future )
@@ -37,6 +39,8 @@ impl<T, Out> LocalNurse<Out> for &mut T
 	where T  : LocalNurse<Out> + ?Sized ,
 	      Out: 'static                  ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_local_obj( future )
@@ -49,6 +53,8 @@ impl<T, Out> LocalNurse<Out> for Box<T>
 	where T  : LocalNurse<Out> + ?Sized ,
 	      Out: 'static                  ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_local_obj( future )
@@ -61,6 +67,8 @@ impl<T, Out> LocalNurse<Out> for Arc<T>
 	where T  : LocalNurse<Out> + ?Sized ,
 	      Out: 'static                  ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_local_obj( future )
@@ -73,6 +81,8 @@ impl<T, Out> LocalNurse<Out> for Rc<T>
 	where T  : LocalNurse<Out> + ?Sized ,
 	      Out: 'static                  ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_local_obj( future )
@@ -85,6 +95,8 @@ impl<T, Out> LocalNurseExt<Out> for T
 	where T  : LocalNurse<Out> + ?Sized ,
 	      Out: 'static                  ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local( &self, future: impl Future<Output = Out> + 'static ) -> Result<(), NurseErr>
 	{
 		self.nurse_local_obj( LocalFutureObj::new( Box::new(future) ) )
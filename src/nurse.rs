@@ -34,6 +34,8 @@ impl<T, Out> Nurse<Out> for &T
 	where T  : Nurse<Out> + ?Sized ,
 	      Out: 'static + Send      ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_obj( future )
@@ -46,6 +48,8 @@ impl<T, Out> Nurse<Out> for &mut T
 	where T  : Nurse<Out> + ?Sized ,
 	      Out: 'static + Send      ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_obj( future )
@@ -58,6 +62,8 @@ impl<T, Out> Nurse<Out> for Box<T>
 	where T  : Nurse<Out> + ?Sized ,
 	      Out: 'static + Send      ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_obj( future )
@@ -70,6 +76,8 @@ impl<T, Out> Nurse<Out> for Arc<T>
 	where T  : Nurse<Out> + ?Sized ,
 	      Out: 'static + Send      ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_obj( future )
@@ -82,6 +90,8 @@ impl<T, Out> Nurse<Out> for Rc<T>
 	where T  : Nurse<Out> + ?Sized ,
 	      Out: 'static + Send      ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		(**self).nurse_obj( future )
@@ -94,6 +104,8 @@ impl<T, Out> NurseExt<Out> for T
 	where T  : Nurse<Out> + ?Sized ,
 	      Out: 'static + Send      ,
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse( &self, future: impl Future<Output = Out> + Send + 'static ) -> Result<(), NurseErr>
 	{
 		self.nurse_obj( FutureObj::new( Box::new(future) ) )
@@ -1,15 +1,59 @@
-use crate:: { import::*, Nurse, LocalNurse, NurseErr, NurseryStream };
+use crate::
+{
+	import::*, Nurse, LocalNurse, NurseErr, NurseryStream, TryNurseryStream, CancelToken, TaskHandle,
+	TaskId, PanicPolicy, bound::{ Bound, Permit }, deadline::{ Deadline, TimedOut }, entry::{ Entry, Message, Name }, registry::{ registry, Sender }, task_handle::TaskSlot, task_id::IdGen,
+	panic_policy::{ PanicSlot, wrap_blocking }, counters::{ Counters, Tracker }, task_builder::{ TaskBuilder, TaskMeta },
+};
+
+#[ cfg( feature = "metrics" ) ]
+//
+use crate::{ NurseryObserver, observer::Observer };
 
 
-/// The sender part of the nursery. Wraps an unbounded sender. Can be cloned.
+/// A freshly spawned task, with the slot where it stores a panic if a [`PanicPolicy`] requires one.
+//
+struct Spawned<H>
+{
+	id       : TaskId                 ,
+	handle   : H                      ,
+	panic    : Option<Arc<PanicSlot>> ,
+	critical : bool                   ,
+	timed_out: Option<TimedOut>       ,
+	name     : Name                   ,
+
+	#[ cfg( feature = "tracing" ) ]
+	//
+	span     : Span                   ,
+}
+
+
+/// The sender part of the nursery. Can be cloned.
 /// To manage the spawned tasks and await their output, see [`NurseryStream`].
 ///
 /// Will disconnect on drop. You can close all senders by calling `close_nursery`.
 ///
+/// Every nursery has a [`CancelToken`] for cooperative cancellation, shared with it's clones and
+/// the `NurseryStream`. Tasks that want to do cleanup when cancelled can get it with
+/// [`Nursery::cancel_token`].
+///
+/// A nursery created with [`Nursery::bounded`] limits the number of tasks in flight. See it's
+/// documentation for how to wait for a slot to become available.
+///
+/// What happens when a task panics can be configured with [`Nursery::set_panic_policy`].
+///
+/// With the `tracing` feature, every nursery creates a span, which is a child of the current span
+/// when the nursery is created, or of the span of the parent for [child nurseries](Nursery::child).
+/// Every task runs in a span of it's own, within the span of it's nursery, with the [`TaskId`] and the
+/// location where it was spawned. Events are emitted when tasks are spawned, finish, panic or are dropped
+/// and when the nursery is cancelled.
+///
+/// For tasks that return a `Result`, [`Nursery::with_deadline`] and [`Nursery::nurse_timeout`] limit
+/// how long tasks can run. Tasks that don't finish in time are dropped and yield [`NurseErr::Timeout`].
+///
 /// Will implement async_executor traits if the executor does. Forwards [`Timer`], [`TokioIo`],
 /// [`YieldNow`] and [`SpawnBlocking`]. Note that the nursery doesn't actually manage the
 /// tasks spawned via `SpawnBlocking`. It just let's you use that functionality of the wrapped
-/// executor.
+/// executor. Use [`Nursery::nurse_blocking`] for blocking tasks managed by the nursery.
 //
 #[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
 //
@@ -18,7 +62,27 @@ use crate:: { import::*, Nurse, LocalNurse, NurseErr, NurseryStream };
 pub struct Nursery<S, Out>
 {
 	spawner     : S                                ,
-	tx          : UnboundedSender<JoinHandle<Out>> ,
+	tx          : Sender< Message<Out> >           ,
+	token       : CancelToken                      ,
+	ids         : Arc<IdGen>                       ,
+	bound       : Option< Arc<Bound> >             ,
+	panic       : Option< PanicPolicy<Out> >       ,
+	deadline    : Option< Deadline<Out> >          ,
+	counters    : Arc<Counters>                    ,
+
+	#[ cfg( feature = "metrics" ) ]
+	//
+	observer    : Option<Observer>                 ,
+
+	#[ cfg( feature = "tracing" ) ]
+	//
+	span        : Span                             ,
+
+	// State for the Sink impl on bounded nurseries. A permit acquired in `poll_ready`
+	// and the key of our waker while waiting for one.
+	//
+	reserved    : Option<Permit>                   ,
+	waiting     : Option<usize>                    ,
 }
 
 
@@ -28,8 +92,43 @@ impl<S, Out> Clone for Nursery<S, Out> where S: Clone
 	{
 		Self
 		{
-			spawner: self.spawner.clone() ,
-			tx     : self.tx     .clone() ,
+			spawner : self.spawner.clone() ,
+			tx      : self.tx     .clone() ,
+			token   : self.token  .clone() ,
+			ids     : self.ids    .clone() ,
+			bound   : self.bound  .clone() ,
+			panic   : self.panic  .clone() ,
+			deadline: self.deadline.clone(),
+			counters: self.counters.clone(),
+			reserved: None                 ,
+			waiting : None                 ,
+
+			#[ cfg( feature = "metrics" ) ]
+			//
+			observer: self.observer.clone(),
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span    : self.span    .clone(),
+		}
+	}
+}
+
+
+
+// We never pin project to our fields.
+//
+impl<S, Out> Unpin for Nursery<S, Out> {}
+
+
+
+impl<S, Out> Drop for Nursery<S, Out>
+{
+	fn drop( &mut self )
+	{
+		if let Some(bound) = &self.bound
+		{
+			bound.cancel_acquire( &mut self.waiting );
 		}
 	}
 }
@@ -45,35 +144,833 @@ impl<S, Out> Nursery<S, Out>
 
 		where Out: 'static
 	{
-		let (tx, rx) = unbounded();
+		let (tx, rx) = registry();
+		let token    = CancelToken::new();
+		let counters = Arc::new( Counters::default() );
+		let stream   = NurseryStream::new( rx, token.clone(), counters.clone() );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let span = tracing_crate::info_span!( "nursery" );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let stream = stream.in_span( span.clone() );
 
 		(
-			Self{ spawner, tx }      ,
-			NurseryStream::new( rx ) ,
+			Self
+			{
+				spawner                   ,
+				tx                        ,
+				token   : token.clone()   ,
+				ids     : Arc::default()  ,
+				bound   : None            ,
+				panic   : None            ,
+				deadline: None            ,
+				counters: counters.clone(),
+				reserved: None            ,
+				waiting : None            ,
+
+				#[ cfg( feature = "metrics" ) ]
+				//
+				observer: None            ,
+
+				#[ cfg( feature = "tracing" ) ]
+				//
+				span                      ,
+			},
+
+			stream,
 		)
 	}
 
 
+	/// Create a new nursery of which the [`NurseryStream`] yields outputs in the order the tasks
+	/// were spawned. Shorthand for [`Nursery::new`] followed by [`NurseryStream::set_ordered`], see
+	/// there for the details.
+	//
+	pub fn new_ordered( spawner: S ) -> (Self, NurseryStream<Out>)
+
+		where Out: 'static
+	{
+		let (nursery, mut stream) = Self::new( spawner );
+
+		stream.set_ordered();
+
+		(nursery, stream)
+	}
+
+
+	/// Create a new nursery that allows at most `max_in_flight` tasks to run at the same time.
+	/// Shorthand for [`Nursery::new`] followed by [`Nursery::set_bound`], see there for the details.
+	///
+	/// # Panics
+	///
+	/// When `max_in_flight` is 0, as no task could ever be spawned.
+	//
+	pub fn bounded( spawner: S, max_in_flight: usize ) -> (Self, NurseryStream<Out>)
+
+		where Out: 'static
+	{
+		let (mut nursery, stream) = Self::new( spawner );
+
+		nursery.set_bound( max_in_flight );
+
+		(nursery, stream)
+	}
+
+
+	/// Create a child nursery. It's [`NurseryStream`] is managed by this nursery, so the outputs of
+	/// it's tasks are yielded by the stream of this nursery, which only ends once the child nursery
+	/// is closed and all of it's tasks are done.
+	///
+	/// The child has it's own [`CancelToken`], which is a [child](CancelToken::child_token) of
+	/// the token of this nursery. [Cancelling](NurseryStream::cancel) the parent cancels and closes
+	/// all descendants, while the child can be cancelled without affecting the parent. Dropping the
+	/// parent `NurseryStream` drops the tasks of all descendants.
+	///
+	/// The child shares the limit of a [bounded](Nursery::bounded) nursery, the [deadline](Nursery::with_deadline)
+	/// and the [`PanicPolicy`] with this nursery. A panic that ends the child with
+	/// [`PanicPolicy::CancelSiblings`] ends the parent as well. The tasks of the child are included
+	/// in the [counters](Nursery::active_tasks) of this nursery.
+	///
+	/// In [ordered](Nursery::new_ordered) mode, outputs of child nurseries are not part of the ordering.
+	//
+	pub fn child( &self ) -> Result<Self, NurseErr>
+
+		where S: Clone, Out: 'static
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let (tx, rx) = registry();
+		let token    = self.token.child_token();
+		let counters = self.counters.child();
+		let stream   = NurseryStream::new( rx, token.clone(), counters.clone() );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let span = tracing_crate::info_span!( parent: &self.span, "nursery" );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let stream = stream.in_span( span.clone() );
+
+		self.tx.send( Message::Child( Box::new( stream ) ) )?;
+
+		Ok( Self
+		{
+			spawner : self.spawner .clone() ,
+			tx                              ,
+			token                           ,
+			ids     : self.ids     .clone() ,
+			bound   : self.bound   .clone() ,
+			panic   : self.panic   .clone() ,
+			deadline: self.deadline.clone() ,
+			counters                        ,
+			reserved: None                  ,
+			waiting : None                  ,
+
+			#[ cfg( feature = "metrics" ) ]
+			//
+			observer: self.observer.clone() ,
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span                            ,
+		})
+	}
+
+
+	/// Get the [`CancelToken`] of this nursery. Pass it to tasks that need to do cleanup
+	/// when [`NurseryStream::cancel`] is called. Holding a token does not keep the nursery open.
+	//
+	pub fn cancel_token( &self ) -> CancelToken
+	{
+		self.token.clone()
+	}
+
+
+	/// Spawn a task with options, like a name or a timeout. See [`TaskBuilder`].
+	//
+	pub fn task( &self ) -> TaskBuilder<'_, S, Out>
+	{
+		TaskBuilder::new( self )
+	}
+
+
+	/// Set what happens when a task spawned on this nursery panics. See [`PanicPolicy`] for the options.
+	///
+	/// The policy applies to tasks spawned after this call, on this nursery and on clones made from it
+	/// afterwards. It does not apply to handles added with [`Nursery::nurse_handle`], as those tasks are
+	/// already running.
+	//
+	pub fn set_panic_policy( &mut self, policy: PanicPolicy<Out> ) -> &mut Self
+	{
+		self.panic = Some( policy );
+		self
+	}
+
+
+	/// Allow at most `max_in_flight` tasks to run at the same time. A slot is freed as soon as a task
+	/// finishes or is dropped.
+	///
+	/// When the limit is reached, [`nurse`](crate::NurseExt::nurse) returns [`NurseErr::Full`].
+	/// To wait for a slot instead, use [`Nursery::nurse_async`] or the [`Sink`] impl, whose
+	/// `poll_ready` only resolves once a slot is available.
+	///
+	/// Like the panic policy, the limit applies to tasks spawned after this call, on this nursery and
+	/// on clones and [child nurseries](Nursery::child) made from it afterwards, which all share it.
+	/// Handles added with [`Nursery::nurse_handle`] are not counted, since their task is already running.
+	///
+	/// # Panics
+	///
+	/// When `max_in_flight` is 0, as no task could ever be spawned.
+	//
+	pub fn set_bound( &mut self, max_in_flight: usize ) -> &mut Self
+	{
+		assert!( max_in_flight > 0, "A bounded nursery must allow at least one task in flight." );
+
+		// Stop waiting for a slot on the previous limit, if any.
+		//
+		if let Some(bound) = &self.bound
+		{
+			bound.cancel_acquire( &mut self.waiting );
+		}
+
+		self.bound = Some( Bound::new( max_in_flight ) );
+		self
+	}
+
+
+	/// Set a [`NurseryObserver`] that receives events about the tasks spawned on this nursery,
+	/// to record metrics.
+	///
+	/// Like the panic policy, the observer applies to tasks spawned after this call, on this nursery
+	/// and on clones and [child nurseries](Nursery::child) made from it afterwards.
+	//
+	#[ cfg( feature = "metrics" ) ]
+	#[ cfg_attr( nightly, doc(cfg( feature = "metrics" )) ) ]
+	//
+	pub fn set_observer( &mut self, observer: Arc<dyn NurseryObserver> ) -> &mut Self
+	{
+		self.observer = Some( Observer::new( observer ) );
+		self
+	}
+
+
+	/// Spawn a future, waiting for a slot to become available on a bounded nursery. On a nursery
+	/// without a limit this is the same as [`nurse`](crate::NurseExt::nurse).
+	//
+	pub async fn nurse_async( &self, fut: impl Future<Output = Out> + Send + 'static ) -> Result<(), NurseErr>
+
+		where S: SpawnHandle<Out>, Out: 'static + Send
+	{
+		let permit = match &self.bound
+		{
+			Some(bound) => Some( bound.acquire().await ),
+			None        => None,
+		};
+
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_obj( FutureObj::new( Box::new(fut) ), permit, &TaskMeta::default() )?;
+
+		self.send( task )?;
+
+		Ok(())
+	}
+
+
+	/// Take a slot on a bounded nursery. Returns `None` if this nursery is not bounded.
+	//
+	fn try_permit( &self ) -> Result<Option<Permit>, NurseErr>
+	{
+		match &self.bound
+		{
+			Some(bound) => bound.try_acquire().map( Some ).ok_or( NurseErr::Full ),
+			None        => Ok( None ),
+		}
+	}
+
+
+	/// Wait for a slot on a bounded nursery and keep it for the next call to `start_send`.
+	//
+	fn poll_reserve( &mut self, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		if let Some(bound) = &self.bound
+		{
+			if self.reserved.is_none()
+			{
+				self.reserved = Some( ready!( bound.poll_acquire( cx, &mut self.waiting ) ) );
+			}
+		}
+
+		Poll::Ready(())
+	}
+
+
+	/// Spawn a future. The permit, if any, will be held by the task until it finishes or panics.
+	/// The caller is responsible for sending the handle to the `NurseryStream`.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	fn spawn_obj( &self, fut: FutureObj<'static, Out>, permit: Option<Permit>, meta: &TaskMeta ) -> Result<Spawned< JoinHandle<Out> >, NurseErr>
+
+		where S: SpawnHandle<Out>, Out: 'static + Send
+	{
+		let id = self.ids.next();
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let span = self.task_span( id, meta );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let fut = FutureObj::new( Box::new( fut.instrument( span.clone() ) ) );
+
+		#[ cfg( feature = "metrics" ) ]
+		//
+		let fut = match &self.observer
+		{
+			None           => fut,
+			Some(observer) => FutureObj::new( Box::new( observer.wrap( fut ) ) ),
+		};
+
+		let mut timed_out = meta.timed_out.clone();
+
+		let fut = match &self.deadline
+		{
+			None                                    => fut,
+			Some(deadline) if deadline.has_passed() => return Err( NurseErr::Timeout ),
+			Some(deadline)                          =>
+			{
+				let timed_out = timed_out.get_or_insert_with( TimedOut::default ).clone();
+
+				FutureObj::new( Box::new( deadline.wrap( fut, timed_out ) ) )
+			}
+		};
+
+		let (handle, panic) = match ( &self.panic, permit )
+		{
+			( None, None         ) => ( self.spawner.spawn_handle_obj( fut )?, None ),
+			( None, Some(permit) ) =>
+			{
+				let fut = async move { let _permit = permit; fut.await };
+
+				( self.spawner.spawn_handle_obj( FutureObj::new( Box::new(fut) ) )?, None )
+			}
+
+			( Some(policy), permit ) =>
+			{
+				let (fut, slot) = policy.wrap( fut, permit );
+
+				( self.spawner.spawn_handle_obj( FutureObj::new( Box::new(fut) ) )?, slot )
+			}
+		};
+
+		Ok( Spawned
+		{
+			id                       ,
+			handle                   ,
+			panic                    ,
+			critical : meta.critical ,
+			timed_out                ,
+			name     : meta.name.clone(),
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span                     ,
+		})
+	}
+
+
+	/// Spawn a `!Send` future. The permit, if any, will be held by the task until it finishes or panics.
+	/// The caller is responsible for sending the handle to the `NurseryStream`.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	fn spawn_local_obj( &self, fut: LocalFutureObj<'static, Out>, permit: Option<Permit>, meta: &TaskMeta ) -> Result<Spawned< JoinHandle<Out> >, NurseErr>
+
+		where S: LocalSpawnHandle<Out>, Out: 'static
+	{
+		let id = self.ids.next();
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let span = self.task_span( id, meta );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let fut = LocalFutureObj::new( Box::new( fut.instrument( span.clone() ) ) );
+
+		#[ cfg( feature = "metrics" ) ]
+		//
+		let fut = match &self.observer
+		{
+			None           => fut,
+			Some(observer) => LocalFutureObj::new( Box::new( observer.wrap( fut ) ) ),
+		};
+
+		let mut timed_out = meta.timed_out.clone();
+
+		let fut = match &self.deadline
+		{
+			None                                    => fut,
+			Some(deadline) if deadline.has_passed() => return Err( NurseErr::Timeout ),
+			Some(deadline)                          =>
+			{
+				let timed_out = timed_out.get_or_insert_with( TimedOut::default ).clone();
+
+				LocalFutureObj::new( Box::new( deadline.wrap( fut, timed_out ) ) )
+			}
+		};
+
+		let (handle, panic) = match ( &self.panic, permit )
+		{
+			( None, None         ) => ( self.spawner.spawn_handle_local_obj( fut )?, None ),
+			( None, Some(permit) ) =>
+			{
+				let fut = async move { let _permit = permit; fut.await };
+
+				( self.spawner.spawn_handle_local_obj( LocalFutureObj::new( Box::new(fut) ) )?, None )
+			}
+
+			( Some(policy), permit ) =>
+			{
+				let (fut, slot) = policy.wrap( fut, permit );
+
+				( self.spawner.spawn_handle_local_obj( LocalFutureObj::new( Box::new(fut) ) )?, slot )
+			}
+		};
+
+		Ok( Spawned
+		{
+			id                       ,
+			handle                   ,
+			panic                    ,
+			critical : meta.critical ,
+			timed_out                ,
+			name     : meta.name.clone(),
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span                     ,
+		})
+	}
+
+
 	/// When dealing with an API that takes `SpawnHandle` and returns you a `JoinHandle`, you can use this
 	/// method to add the `JoinHandle` to your nursery.
 	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	pub fn nurse_handle( &self, handle: JoinHandle<Out> ) -> Result<(), NurseErr>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
 
-		self.tx.unbounded_send( handle )?;
+		let id = self.ids.next();
+
+		self.send( Spawned
+		{
+			id               ,
+			handle           ,
+			panic    : None  ,
+			critical : true  ,
+			timed_out: None  ,
+			name     : None  ,
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span     : self.task_span( id, &TaskMeta::default() ),
+		})?;
 
 		Ok(())
 	}
 
 
+	/// Spawn a future and get the [`TaskId`] assigned to it. Use [`NurseryStream::with_ids`] to
+	/// get the id together with the output.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_with_id( &self, fut: impl Future<Output = Out> + Send + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnHandle<Out>, Out: 'static + Send
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_obj( FutureObj::new( Box::new(fut) ), self.try_permit()?, &TaskMeta::default() )?;
+
+		self.send( task )
+	}
+
+
+	/// Spawn a `!Send` future and get the [`TaskId`] assigned to it. See [`Nursery::nurse_with_id`].
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_local_with_id( &self, fut: impl Future<Output = Out> + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: LocalSpawnHandle<Out>, Out: 'static
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_local_obj( LocalFutureObj::new( Box::new(fut) ), self.try_permit()?, &TaskMeta::default() )?;
+
+		self.send( task )
+	}
+
+
+	/// Spawn a future with the metadata from a [`TaskBuilder`].
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub(crate) fn nurse_meta( &self, fut: FutureObj<'static, Out>, meta: &TaskMeta ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnHandle<Out>, Out: 'static + Send
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_obj( fut, self.try_permit()?, meta )?;
+
+		self.send( task )
+	}
+
+
+	/// Spawn a `!Send` future with the metadata from a [`TaskBuilder`].
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub(crate) fn nurse_local_meta( &self, fut: LocalFutureObj<'static, Out>, meta: &TaskMeta ) -> Result<TaskId, NurseErr>
+
+		where S: LocalSpawnHandle<Out>, Out: 'static
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_local_obj( fut, self.try_permit()?, meta )?;
+
+		self.send( task )
+	}
+
+
+	/// Run a closure on the thread pool of the executor for blocking work. The nursery manages it
+	/// like any other task: the `NurseryStream` yields it's output and waits for it to finish.
+	///
+	/// Blocking tasks can't be dropped, so cancelling the nursery or dropping the `NurseryStream` has no
+	/// effect on a closure that is running. Use [`Nursery::nurse_blocking_with_token`] for a closure that can
+	/// stop early. For the same reason, the [deadline](Nursery::with_deadline) of the nursery doesn't apply
+	/// to blocking tasks.
+	///
+	/// Panics are caught on the blocking thread and handled according to the [`PanicPolicy`]. Without
+	/// a policy, they resume when polling the `NurseryStream`.
+	///
+	/// Use [`TaskBuilder::spawn_blocking`] to give the task a name or to run it in the background.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_blocking( &self, f: impl FnOnce() -> Out + Send + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnBlocking< std::thread::Result<Out> >, Out: 'static + Send
+	{
+		self.task().spawn_blocking( f )
+	}
+
+
+	/// Run a closure for blocking work, see [`Nursery::nurse_blocking`]. The closure gets a [`CancelToken`]
+	/// that is cancelled with the nursery or when the `NurseryStream` is dropped. Check
+	/// [`CancelToken::is_cancelled`] regularly to stop early.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_blocking_with_token( &self, f: impl FnOnce( CancelToken ) -> Out + Send + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnBlocking< std::thread::Result<Out> >, Out: 'static + Send
+	{
+		self.task().spawn_blocking_with_token( f )
+	}
+
+
+	/// Run a blocking closure with the metadata from a [`TaskBuilder`].
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub(crate) fn nurse_blocking_meta( &self, f: impl FnOnce( CancelToken ) -> Out + Send + 'static, meta: &TaskMeta ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnBlocking< std::thread::Result<Out> >, Out: 'static + Send
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let permit = self.try_permit()?;
+		let id     = self.ids.next();
+		let token  = self.token.child_token();
+		let child  = token.clone();
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let span = self.task_span( id, meta );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let task_span = span.clone();
+
+		let (f, on_panic) = wrap_blocking( self.panic.as_ref(), move ||
+		{
+			#[ cfg( feature = "tracing" ) ]
+			//
+			let _entered = task_span.enter();
+
+			let _permit = permit;
+
+			f( child )
+		});
+
+		let task = Spawned
+		{
+			id               ,
+			handle   : self.spawner.spawn_blocking( f ),
+			panic    : None              ,
+			critical : meta.critical     ,
+			timed_out: None              ,
+			name     : meta.name.clone() ,
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span             ,
+		};
+
+		let tracker = self.track( &task );
+
+		self.tx.send( Entry::blocking( task.id, task.handle, on_panic, token, task.critical, tracker ).named( task.name ).into() )?;
+		self.counters.spawned();
+
+		Ok( task.id )
+	}
+
+
+	/// Spawn a future and get a [`TaskHandle`] to it. The output of the task will go to the handle
+	/// instead of the [`NurseryStream`]. The handle let's you abort this task or await it's output
+	/// individually. The nursery still makes sure the task is done or dropped before the
+	/// `NurseryStream` finishes.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_with_handle( &self, fut: impl Future<Output = Out> + Send + 'static ) -> Result<TaskHandle<Out>, NurseErr>
+
+		where S: SpawnHandle<Out>, Out: 'static + Send
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_obj( FutureObj::new( Box::new(fut) ), self.try_permit()?, &TaskMeta::default() )?;
+
+		self.register_slot( task )
+	}
+
+
+	/// Spawn a `!Send` future and get a [`TaskHandle`] to it. See [`Nursery::nurse_with_handle`].
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_local_with_handle( &self, fut: impl Future<Output = Out> + 'static ) -> Result<TaskHandle<Out>, NurseErr>
+
+		where S: LocalSpawnHandle<Out>, Out: 'static
+	{
+		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
+
+		let task = self.spawn_local_obj( LocalFutureObj::new( Box::new(fut) ), self.try_permit()?, &TaskMeta::default() )?;
+
+		self.register_slot( task )
+	}
+
+
+	/// Send a task to the `NurseryStream` with it's output going to a `TaskHandle`.
+	//
+	fn register_slot( &self, task: Spawned< JoinHandle<Out> > ) -> Result<TaskHandle<Out>, NurseErr>
+	{
+		let tracker = self.track( &task );
+		let slot    = TaskSlot::new( task.handle );
+
+		self.tx.send( Entry::slot( task.id, slot.clone(), task.panic, task.critical, tracker ).named( task.name ).into() )?;
+		self.counters.spawned();
+
+		Ok( TaskHandle::new( task.id, slot ) )
+	}
+
+
+	/// Send a task to the `NurseryStream`. Returns the id assigned to it.
+	//
+	fn send( &self, task: Spawned< JoinHandle<Out> > ) -> Result<TaskId, NurseErr>
+	{
+		let tracker = self.track( &task );
+
+		self.tx.send( Entry::handle( task.id, task.handle, task.panic, task.critical, task.timed_out, tracker ).named( task.name ).into() )?;
+		self.counters.spawned();
+
+		Ok( task.id )
+	}
+
+
+	/// Start tracking a task that is about to be sent to the `NurseryStream`.
+	//
+	#[ cfg_attr( not(any( feature = "metrics", feature = "tracing" )), allow( unused_variables ) ) ]
+	//
+	fn track<H>( &self, task: &Spawned<H> ) -> Tracker
+	{
+		let tracker = self.counters.track();
+
+		#[ cfg( feature = "metrics" ) ]
+		//
+		let tracker = tracker.observed( self.observer.as_ref().map( |o| o.spawned( task.id ) ) );
+
+		#[ cfg( feature = "tracing" ) ]
+		//
+		let tracker = tracker.in_span( task.span.clone() );
+
+		tracker
+	}
+
+
+	/// Create the span of a task, within the span of this nursery.
+	//
+	#[ cfg( feature = "tracing" ) ]
+	#[ track_caller ]
+	//
+	fn task_span( &self, id: TaskId, meta: &TaskMeta ) -> Span
+	{
+		let location = std::panic::Location::caller();
+
+		tracing_crate::info_span!( parent: &self.span, "task", id = id.as_u64(), name = meta.name.as_deref(), critical = meta.critical, %location )
+	}
+
+
+	/// The number of tasks that were spawned and haven't finished or been dropped yet. This includes
+	/// the [queued](Nursery::queued_handles) ones and tasks of which the output goes to a [`TaskHandle`],
+	/// as well as the tasks of [child nurseries](Nursery::child).
+	//
+	pub fn active_tasks( &self ) -> usize
+	{
+		self.counters.active_tasks()
+	}
+
+
+	/// The number of tasks that were spawned, but that the [`NurseryStream`] hasn't picked up yet.
+	/// It picks them up when it is polled.
+	//
+	pub fn queued_handles( &self ) -> usize
+	{
+		self.counters.queued_handles()
+	}
+
+
+	/// The number of tasks that finished since the nursery was created, including the ones that timed out or
+	/// of which a panic was caught by the [`PanicPolicy`]. Tasks that are dropped, eg. because the
+	/// `NurseryStream` is dropped, are not counted.
+	//
+	pub fn completed_total( &self ) -> u64
+	{
+		self.counters.completed_total()
+	}
+
+
+	/// The number of tasks that were spawned since the nursery was created.
+	//
+	pub fn spawned_total( &self ) -> u64
+	{
+		self.counters.spawned_total()
+	}
+
+
 	/// Stop this nursery and any clones from accepting any more tasks. Calling this or
 	/// dropping all `Nursery` is necessary for the stream impl of `NurseryStream` to end
 	/// and return `None`.
 	//
 	pub fn close_nursery( &self )
 	{
-		self.tx.close_channel();
+		self.tx.close();
+	}
+}
+
+
+
+impl<S, T, E> Nursery<S, Result<T, E>>
+{
+	/// Create a new nursery for tasks that return a `Result`. The [`TryNurseryStream`] resolves
+	/// to the outputs of all tasks, or to the first error, in which case all other tasks are canceled.
+	//
+	pub fn new_try( spawner: S ) -> (Self, TryNurseryStream<T, E>)
+
+		where T: 'static, E: 'static
+	{
+		let (nursery, stream) = Self::new( spawner );
+
+		(nursery, stream.into())
+	}
+
+
+	/// Create a new nursery with a deadline. Shorthand for [`Nursery::new`] followed by
+	/// [`Nursery::set_deadline`], see there for the details.
+	//
+	pub fn with_deadline( spawner: S, after: Duration ) -> (Self, NurseryStream< Result<T, E> >)
+
+		where S: Timer, T: 'static, E: From<NurseErr> + 'static
+	{
+		let (mut nursery, stream) = Self::new( spawner );
+
+		nursery.set_deadline( after );
+
+		(nursery, stream)
+	}
+
+
+	/// Set a deadline. Tasks that are still running `after` the given duration, counted from this call,
+	/// are dropped and yield `Err(NurseErr::Timeout.into())` instead. Once the deadline has passed,
+	/// spawning returns [`NurseErr::Timeout`].
+	///
+	/// Like the panic policy, the deadline applies to tasks spawned after this call, on this nursery and
+	/// on clones and [child nurseries](Nursery::child) made from it afterwards. It uses the [`Timer`] of
+	/// the executor. It does not apply to handles added with [`Nursery::nurse_handle`], as the nursery
+	/// can't observe those tasks while they run.
+	//
+	pub fn set_deadline( &mut self, after: Duration ) -> &mut Self
+
+		where S: Timer, E: From<NurseErr>
+	{
+		let sleep = self.spawner.sleep( after );
+
+		self.deadline = Some( Deadline::new( sleep, || Err( NurseErr::Timeout.into() ) ) );
+		self
+	}
+
+
+	/// Spawn a future that is dropped if it doesn't finish within `dur`, in which case it yields
+	/// `Err(NurseErr::Timeout.into())`. On a nursery with a deadline, whichever expires first applies.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_timeout( &self, dur: Duration, fut: impl Future< Output = Result<T, E> > + Send + 'static ) -> Result<(), NurseErr>
+
+		where S: SpawnHandle< Result<T, E> > + Timer, T: Send + 'static, E: From<NurseErr> + Send + 'static
+	{
+		let deadline  = Deadline::new( self.spawner.sleep( dur ), || Err( NurseErr::Timeout.into() ) );
+		let timed_out = TimedOut::default();
+		let meta      = TaskMeta{ timed_out: Some( timed_out.clone() ), ..TaskMeta::default() };
+
+		self.nurse_meta( FutureObj::new( Box::new( deadline.wrap( fut, timed_out ) ) ), &meta ).map( |_| () )
+	}
+
+
+	/// Spawn a `!Send` future that is dropped if it doesn't finish within `dur`. See [`Nursery::nurse_timeout`].
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn nurse_local_timeout( &self, dur: Duration, fut: impl Future< Output = Result<T, E> > + 'static ) -> Result<(), NurseErr>
+
+		where S: LocalSpawnHandle< Result<T, E> > + Timer, T: 'static, E: From<NurseErr> + 'static
+	{
+		let deadline  = Deadline::new( self.spawner.sleep( dur ), || Err( NurseErr::Timeout.into() ) );
+		let timed_out = TimedOut::default();
+		let meta      = TaskMeta{ timed_out: Some( timed_out.clone() ), ..TaskMeta::default() };
+
+		self.nurse_local_meta( LocalFutureObj::new( Box::new( deadline.wrap( fut, timed_out ) ) ), &meta ).map( |_| () )
 	}
 }
 
@@ -81,13 +978,15 @@ impl<S, Out> Nursery<S, Out>
 
 impl<S, Out> Nurse<Out> for Nursery<S, Out> where S: SpawnHandle<Out>, Out: 'static + Send
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_obj( &self, fut: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
 
-		let handle = self.spawner.spawn_handle_obj( fut )?;
+		let task = self.spawn_obj( fut, self.try_permit()?, &TaskMeta::default() )?;
 
-		self.tx.unbounded_send( handle )?;
+		self.send( task )?;
 
 		Ok(())
 	}
@@ -97,13 +996,15 @@ impl<S, Out> Nurse<Out> for Nursery<S, Out> where S: SpawnHandle<Out>, Out: 'sta
 
 impl<S, Out> LocalNurse<Out> for Nursery<S, Out> where S: LocalSpawnHandle<Out>, Out: 'static
 {
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
 	fn nurse_local_obj( &self, fut: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
 
-		let handle = self.spawner.spawn_handle_local_obj( fut )?;
+		let task = self.spawn_local_obj( fut, self.try_permit()?, &TaskMeta::default() )?;
 
-		self.tx.unbounded_send( handle )?;
+		self.send( task )?;
 
 		Ok(())
 	}
@@ -138,19 +1039,31 @@ impl<S, Out> Sink<FutureObj<'static, Out>> for Nursery<S, Out>
 {
 	type Error = NurseErr;
 
-	fn poll_ready( self: Pin<&mut Self>, _cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	/// On a bounded nursery, this resolves once a slot is available.
+	//
+	fn poll_ready( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ).into() }
 
-		Poll::Ready( Ok(()) )
+		self.poll_reserve( cx ).map( Ok )
 	}
 
 
-	fn start_send( self: Pin<&mut Self>, fut: FutureObj<'static, Out> ) -> Result<(), Self::Error>
+	fn start_send( mut self: Pin<&mut Self>, fut: FutureObj<'static, Out> ) -> Result<(), Self::Error>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
 
-		self.nurse_obj( fut )
+		let permit = match self.reserved.take()
+		{
+			Some(permit) => Some( permit ),
+			None         => self.try_permit()?,
+		};
+
+		let task = self.spawn_obj( fut, permit, &TaskMeta::default() )?;
+
+		self.send( task )?;
+
+		Ok(())
 	}
 
 
@@ -160,8 +1073,8 @@ impl<S, Out> Sink<FutureObj<'static, Out>> for Nursery<S, Out>
 	}
 
 
-	/// This is a no-op. If you want to disconnect, just drop this `Nursery`. If you want to
-	/// close the NurseryStream, call [`Nursery::close_nursery`].
+	/// Closes the nursery, see [`Nursery::close_nursery`]. Neither this nursery nor any of it's clones
+	/// accept tasks after this, and the `NurseryStream` ends once the tasks already spawned are done.
 	//
 	fn poll_close( self: Pin<&mut Self>, _cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
 	{
@@ -180,19 +1093,31 @@ impl<S, Out> Sink<LocalFutureObj<'static, Out>> for Nursery<S, Out>
 {
 	type Error = NurseErr;
 
-	fn poll_ready( self: Pin<&mut Self>, _cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	/// On a bounded nursery, this resolves once a slot is available.
+	//
+	fn poll_ready( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ).into() }
 
-		Poll::Ready( Ok(()) )
+		self.poll_reserve( cx ).map( Ok )
 	}
 
 
-	fn start_send( self: Pin<&mut Self>, fut: LocalFutureObj<'static, Out> ) -> Result<(), Self::Error>
+	fn start_send( mut self: Pin<&mut Self>, fut: LocalFutureObj<'static, Out> ) -> Result<(), Self::Error>
 	{
 		if self.tx.is_closed() { return Err( NurseErr::Closed ) }
 
-		self.nurse_local_obj( fut )
+		let permit = match self.reserved.take()
+		{
+			Some(permit) => Some( permit ),
+			None         => self.try_permit()?,
+		};
+
+		let task = self.spawn_local_obj( fut, permit, &TaskMeta::default() )?;
+
+		self.send( task )?;
+
+		Ok(())
 	}
 
 
@@ -202,8 +1127,8 @@ impl<S, Out> Sink<LocalFutureObj<'static, Out>> for Nursery<S, Out>
 	}
 
 
-	/// This is a no-op. If you want to disconnect, just drop this `Nursery`. If you want to
-	/// close the NurseryStream, call [`Nursery::close_nursery`].
+	/// Closes the nursery, see [`Nursery::close_nursery`]. Neither this nursery nor any of it's clones
+	/// accept tasks after this, and the `NurseryStream` ends once the tasks already spawned are done.
 	//
 	fn poll_close( self: Pin<&mut Self>, _cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
 	{
@@ -0,0 +1,160 @@
+use crate:: { import::*, TaskId, entry::Name };
+
+
+/// The errors of all tasks that failed, returned by [`NurseryStream::collect_all`](crate::NurseryStream::collect_all)
+/// and [`NurseryStream::collect_all_budget`](crate::NurseryStream::collect_all_budget). The errors are
+/// kept in the order the tasks finished, together with the id of the task.
+///
+/// When the error budget ran out, the tasks that were still running were dropped. Their ids are
+/// available from [`NurseryErrors::dropped`]. The names of the tasks that were given one with
+/// [`TaskBuilder::name`](crate::TaskBuilder::name) are available from [`NurseryErrors::name`].
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct NurseryErrors<E>
+{
+	errors : Vec<(TaskId, E)>            ,
+	dropped: Vec<TaskId>                 ,
+	names  : HashMap< TaskId, Arc<str> > ,
+}
+
+
+
+impl<E> NurseryErrors<E>
+{
+	pub(crate) fn new( errors: Vec<(TaskId, Name, E)>, dropped: Vec<(TaskId, Name)> ) -> Self
+	{
+		let mut names = HashMap::new();
+
+		let errors = errors.into_iter().map( |(id, name, e)|
+		{
+			names.extend( name.map( |name| (id, name) ) );
+			(id, e)
+		})
+		.collect();
+
+		let dropped = dropped.into_iter().map( |(id, name)|
+		{
+			names.extend( name.map( |name| (id, name) ) );
+			id
+		})
+		.collect();
+
+		Self{ errors, dropped, names }
+	}
+
+
+	/// The number of tasks that failed.
+	//
+	pub fn len( &self ) -> usize
+	{
+		self.errors.len()
+	}
+
+
+	/// Whether no task failed. This is never the case for the errors returned by this crate.
+	//
+	pub fn is_empty( &self ) -> bool
+	{
+		self.errors.is_empty()
+	}
+
+
+	/// Iterate over the errors.
+	//
+	pub fn iter( &self ) -> impl Iterator<Item = &E>
+	{
+		self.errors.iter().map( |(_, e)| e )
+	}
+
+
+	/// Iterate over the errors together with the id of the task that returned them.
+	//
+	pub fn iter_with_ids( &self ) -> impl Iterator<Item = (TaskId, &E)>
+	{
+		self.errors.iter().map( |(id, e)| (*id, e) )
+	}
+
+
+	/// The ids of the tasks that were dropped because the error budget ran out.
+	//
+	pub fn dropped( &self ) -> &[TaskId]
+	{
+		&self.dropped
+	}
+
+
+	/// The name of a task that failed or was dropped, if it has one.
+	//
+	pub fn name( &self, id: TaskId ) -> Option<&str>
+	{
+		self.names.get( &id ).map( |name| &**name )
+	}
+
+
+	/// Take out the errors together with the id of the task that returned them.
+	//
+	pub fn into_inner( self ) -> Vec<(TaskId, E)>
+	{
+		self.errors
+	}
+}
+
+
+
+impl<E> IntoIterator for NurseryErrors<E>
+{
+	type Item     = E;
+	type IntoIter = std::iter::Map< std::vec::IntoIter<(TaskId, E)>, fn( (TaskId, E) ) -> E >;
+
+	fn into_iter( self ) -> Self::IntoIter
+	{
+		self.errors.into_iter().map( |(_, e)| e )
+	}
+}
+
+
+
+impl<E> std::fmt::Display for NurseryErrors<E>
+
+	where E: std::fmt::Display
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		write!( f, "{} task(s) failed", self.errors.len() )?;
+
+		for (id, e) in &self.errors
+		{
+			match self.name( *id )
+			{
+				Some( name ) => write!( f, "\n  task {id} ({name}): {e}" )?,
+				None         => write!( f, "\n  task {id}: {e}"          )?,
+			}
+		}
+
+		if !self.dropped.is_empty()
+		{
+			write!( f, "\n  {} task(s) dropped after the error budget ran out", self.dropped.len() )?;
+		}
+
+		Ok(())
+	}
+}
+
+
+
+impl<E> std::error::Error for NurseryErrors<E>
+
+	where E: std::error::Error + 'static
+{
+	/// The first error.
+	//
+	fn source( &self ) -> Option<&(dyn std::error::Error + 'static)>
+	{
+		let (_, e) = self.errors.first()?;
+
+		Some( e )
+	}
+}
@@ -1,19 +1,53 @@
-use crate:: { import::* };
+use crate:: { import::*, CancelToken, NurseryErrors, TaskId, TaskOutcome, Outcomes, counters::Counters, entry::{ Entry, Exit, Message, Name, Payload }, panic_policy::OnPanic, registry::Receiver };
+use std  :: { any::Any };
 
 /// Collection of [`JoinHandle`]s of tasks spawned on the nursery. When this is dropped,
 /// all spawned tasks are canceled. You can poll the [`Stream`] implementation on this
 /// to obtain the outputs of your tasks. You can await the [`Future`] implementation if
 /// you don't care about the outputs but just want to wait until all spawned tasks are done.
 ///
+/// A stream created with [`Nursery::new_ordered`](crate::Nursery::new_ordered) yields the outputs in the
+/// order the tasks were spawned instead of the order in which they finish. Tasks still run concurrently.
+///
+/// If your tasks need to do cleanup, you can use [`NurseryStream::cancel`] instead of dropping.
+/// This trips the [`CancelToken`] of the nursery, after which you keep polling the stream
+/// until the tasks have wound down.
+///
+/// When a [`PanicPolicy`](crate::PanicPolicy) is set on the nursery, panics of tasks are handled here.
+/// See it's documentation for the options.
+///
+/// Tasks spawned as [background tasks](crate::TaskBuilder::critical) don't keep the stream alive. As soon as
+/// only background tasks are left, the stream closes the nursery, trips the [`CancelToken`], drops them and
+/// ends, even if a [`Nursery`](crate::Nursery) is still around. So spawn the critical tasks before the
+/// background tasks, or before polling the stream. In ordered mode, background tasks yield their output
+/// as soon as they finish.
+///
 #[ derive( Debug ) ]
 //
 #[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
 //
 pub struct NurseryStream<Out>
 {
-	rx       : UnboundedReceiver<JoinHandle<Out>> ,
-	unordered: FuturesUnordered<JoinHandle<Out>>  ,
+	rx       : Receiver< Message<Out> >           ,
+	unordered: FuturesUnordered < Entry<Out> >    ,
+	children : Vec< NurseryStream<Out> >          ,
 	rx_closed: bool                               ,
+	token    : CancelToken                        ,
+	ordered  : Option< Reorder<Out> >             ,
+	panic    : Option<Payload>                    ,
+	counters : Arc<Counters>                      ,
+
+	/// The background tasks in `unordered`.
+	//
+	background: HashSet<TaskId>                   ,
+
+	/// Tasks we dropped, to be reported by [`Outcomes`].
+	//
+	cancelled: VecDeque<(TaskId, Name)>           ,
+
+	#[ cfg( feature = "tracing" ) ]
+	//
+	span     : Span                               ,
 }
 
 
@@ -22,7 +56,7 @@ impl<Out> NurseryStream<Out>
 {
 	/// Create a new nursery.
 	///
-	pub(crate) fn new( rx: UnboundedReceiver<JoinHandle<Out>> ) -> Self
+	pub(crate) fn new( rx: Receiver< Message<Out> >, token: CancelToken, counters: Arc<Counters> ) -> Self
 
 		where Out: 'static
 	{
@@ -32,11 +66,59 @@ impl<Out> NurseryStream<Out>
 		{
 			unordered         ,
 			rx                ,
+			children : Vec::new(),
 			rx_closed: false  ,
+			token             ,
+			ordered  : None   ,
+			panic    : None   ,
+			counters          ,
+			background: HashSet::new(),
+			cancelled : VecDeque::new(),
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			span     : Span::none(),
 		}
 	}
 
 
+	/// Emit events in the span of the nursery.
+	//
+	#[ cfg( feature = "tracing" ) ]
+	//
+	pub(crate) fn in_span( mut self, span: Span ) -> Self
+	{
+		self.span = span;
+		self
+	}
+
+
+	/// Yield outputs in the order the tasks were spawned, rather than the order in which they finish.
+	/// The tasks still run concurrently, but an output is held back until all tasks spawned before it
+	/// have yielded theirs. [`Nursery::new_ordered`](crate::Nursery::new_ordered) is a shorthand for this.
+	///
+	/// When spawning from several clones of the nursery concurrently, the order is the order in which
+	/// the tasks reach the `NurseryStream`. Tasks the stream already received when this is called keep
+	/// the order in which they were spawned. Has no effect if the stream is already ordered.
+	//
+	pub fn set_ordered( &mut self ) -> &mut Self
+	{
+		if self.ordered.is_some() { return self }
+
+		let mut waiting: Vec<TaskId> = self.unordered.iter()
+
+			.filter( |entry| entry.is_critical() )
+			.map   ( Entry::id                   )
+			.collect()
+		;
+
+		waiting.sort_unstable();
+
+		self.ordered = Some( Reorder{ order: waiting.into(), ..Reorder::default() } );
+		self
+	}
+
+
 	/// Close this NurseryStream. Related [`Nursery`](crate::Nursery) will no longer be able to
 	/// spawn. This allows the stream to end. Alternatively you can drop all related
 	/// [`Nursery`](crate::Nursery) or call [`Nursery::close_nursery`](crate::Nursery::close_nursery).
@@ -46,49 +128,674 @@ impl<Out> NurseryStream<Out>
 		self.rx.close();
 		self
 	}
-}
 
 
+	/// Request cooperative cancellation of all tasks. This trips the [`CancelToken`] of the nursery
+	/// and closes it, so no new tasks can be spawned. The same happens to all
+	/// [child nurseries](crate::Nursery::child).
+	///
+	/// As opposed to dropping the `NurseryStream`, the tasks keep running until they observe the
+	/// cancellation and return. Keep polling the stream (or await it) to let them finish their cleanup.
+	//
+	pub fn cancel( &mut self ) -> &mut Self
+	{
+		#[ cfg( feature = "tracing" ) ]
+		//
+		tracing_crate::info!( parent: &self.span, "nursery cancelled" );
 
-impl<Out> Stream for NurseryStream<Out>
+		self.token.cancel();
 
-	where Out: 'static
-{
-	type Item = Out;
+		for child in self.children.iter_mut()
+		{
+			child.cancel();
+		}
 
-	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+		self.close_nursery()
+	}
+
+
+	/// Get the [`CancelToken`] of this nursery.
+	//
+	pub fn cancel_token( &self ) -> CancelToken
+	{
+		self.token.clone()
+	}
+
+
+	/// The number of tasks this stream is responsible for. See [`Nursery::active_tasks`](crate::Nursery::active_tasks).
+	//
+	pub fn active_tasks( &self ) -> usize
+	{
+		self.counters.active_tasks()
+	}
+
+
+	/// The number of tasks that haven't been picked up by this stream yet. See
+	/// [`Nursery::queued_handles`](crate::Nursery::queued_handles).
+	//
+	pub fn queued_handles( &self ) -> usize
+	{
+		self.counters.queued_handles()
+	}
+
+
+	/// The number of tasks that have finished. See [`Nursery::completed_total`](crate::Nursery::completed_total).
+	//
+	pub fn completed_total( &self ) -> u64
+	{
+		self.counters.completed_total()
+	}
+
+
+	/// The number of tasks that were spawned. See [`Nursery::spawned_total`](crate::Nursery::spawned_total).
+	//
+	pub fn spawned_total( &self ) -> u64
+	{
+		self.counters.spawned_total()
+	}
+
+
+	/// The ids and names of the tasks this stream is polling, including those of it's child nurseries.
+	/// Tasks that are still [queued](NurseryStream::queued_handles) are picked up the next time the stream is polled.
+	//
+	pub fn running_tasks( &self ) -> Vec<(TaskId, Option< Arc<str> >)>
+	{
+		let mut running: Vec<_> = self.unordered.iter().map( Entry::label ).collect();
+
+		for child in &self.children
+		{
+			running.extend( child.running_tasks() );
+		}
+
+		running
+	}
+
+
+	/// Gracefully shut down the nursery. This [cancels](NurseryStream::cancel) the nursery, then
+	/// waits up to `grace` for the tasks to finish their cleanup. Tasks that are still running
+	/// when the grace period expires are dropped.
+	///
+	/// The `timer` is used to wait for the grace period. You can pass the executor or the
+	/// [`Nursery`](crate::Nursery), which forwards [`Timer`] from the executor it wraps.
+	///
+	/// Resolves to a [`ShutdownReport`] with the outputs of the tasks that finished and the ids
+	/// of the tasks that were dropped, as well as the names of those tasks.
+	//
+	pub fn shutdown( mut self, grace: Duration, timer: &impl Timer ) -> impl Future< Output = ShutdownReport<Out> >
+
+		where Out: 'static
+	{
+		self.cancel();
+
+		let mut deadline = timer.sleep( grace );
+		let mut finished = Vec::new();
+		let mut names    = HashMap::new();
+
+		poll_fn( move |cx|
+		{
+			loop
+			{
+				match self.poll_next_with_id( cx )
+				{
+					Poll::Ready( Some( (id, name, out) ) ) =>
+					{
+						names.extend( name.map( |name| (id, name) ) );
+						finished.push( (id, out) );
+					}
+
+					Poll::Pending => break,
+
+					Poll::Ready( None ) =>
+					{
+						return Poll::Ready( ShutdownReport
+						{
+							finished: std::mem::take( &mut finished ),
+							dropped : Vec::new()                     ,
+							names   : std::mem::take( &mut names    ),
+						})
+					}
+				}
+			}
+
+			ready!( deadline.poll_unpin( cx ) );
+
+			// In ordered mode, finished tasks might be waiting for one that was spawned earlier.
+			//
+			if let Some(reorder) = &mut self.ordered
+			{
+				for (id, name, out) in reorder.take_finished()
+				{
+					names.extend( name.map( |name| (id, name) ) );
+					finished.push( (id, out) );
+				}
+			}
+
+			let dropped = self.drop_tasks( cx ).into_iter().map( |(id, name)|
+			{
+				names.extend( name.map( |name| (id, name) ) );
+				id
+			})
+			.collect();
+
+			Poll::Ready( ShutdownReport
+			{
+				finished: std::mem::take( &mut finished ),
+				dropped                                  ,
+				names   : std::mem::take( &mut names    ),
+			})
+		})
+	}
+
+
+	/// Resolve with the first output, from whichever task finishes first. The other tasks are dropped,
+	/// the [`CancelToken`] is tripped and the nursery is closed, so nothing spawned after that gets to run.
+	/// The nursery can keep spawning while the race is on, eg. to start alternatives with a delay.
+	///
+	/// Resolves to `None` if the stream ends without an output, eg. when no task was spawned.
+	//
+	pub fn race( mut self ) -> impl Future< Output = Option<Out> >
+
+		where Out: 'static
+	{
+		poll_fn( move |cx|
+		{
+			let out = ready!( self.poll_next_with_id( cx ) );
+
+			self.abort( cx );
+
+			Poll::Ready( out.map( |(_, _, out)| out ) )
+		})
+	}
+
+
+	/// Take the payload of the panic that ended this stream, when the nursery has
+	/// [`PanicPolicy::CancelSiblings`](crate::PanicPolicy::CancelSiblings). Returns `None` if no task panicked.
+	//
+	pub fn take_panic( &mut self ) -> Option< Box<dyn Any + Send> >
+	{
+		self.panic.take().map( Payload::into_inner )
+	}
+
+
+	/// Yield the id of each task together with it's output.
+	//
+	pub fn with_ids( self ) -> WithIds<Out>
+	{
+		WithIds{ inner: self }
+	}
+
+
+	/// Yield a [`TaskOutcome`] for every task, together with it's id and name. See [`Outcomes`].
+	//
+	pub fn outcomes( self ) -> Outcomes<Out>
+	{
+		Outcomes::new( self )
+	}
+
+
+	/// Close the nursery and drop all tasks, including the ones still in the registry.
+	/// Returns the ids and names of the tasks dropped. The stream will be terminated after this.
+	//
+	pub(crate) fn drop_tasks( &mut self, cx: &mut Context<'_> ) -> Vec<(TaskId, Name)>
+	{
+		self.rx.close();
+
+		let mut dropped: Vec<(TaskId, Name)> = self.unordered.iter().map( Entry::label ).collect();
+
+		for child in self.children.iter_mut()
+		{
+			dropped.extend( child.drop_tasks( cx ) );
+		}
+
+		// The registry is closed, but there might still be handles in it that we haven't seen.
+		//
+		while let Poll::Ready( Some(msg) ) = self.rx.poll_recv( cx )
+		{
+			match msg
+			{
+				Message::Task ( entry     ) => dropped.push( entry.label() ),
+				Message::Child( mut child ) => dropped.extend( child.drop_tasks( cx ) ),
+			}
+		}
+
+		self.unordered.clear();
+		self.children.clear();
+		self.background.clear();
+		self.rx_closed = true;
+
+		if let Some(reorder) = &mut self.ordered
+		{
+			*reorder = Reorder::default();
+		}
+
+		dropped
+	}
+
+
+	/// Whether there are no tasks left. Only tells us that all tasks are done right after the stream
+	/// returned `Pending`, as new tasks might be in the registry.
+	//
+	pub(crate) fn is_idle( &self ) -> bool
+	{
+		let waiting = self.ordered.as_ref().is_some_and( |r| !r.done.is_empty() );
+
+		self.unordered.is_empty() && self.children.is_empty() && self.cancelled.is_empty() && !waiting
+	}
+
+
+	/// Poll the streams of child nurseries. Returns the first task that ended. Children that have ended
+	/// are removed.
+	//
+	fn poll_children( &mut self, cx: &mut Context<'_> ) -> Option<(TaskId, Name, Exit<Out>)>
+
+		where Out: 'static
+	{
+		let mut i = 0;
+
+		while i < self.children.len()
+		{
+			match self.children[i].poll_exit( cx )
+			{
+				Poll::Pending             => i += 1,
+				Poll::Ready( Some(exit) ) => return Some( exit ),
+				Poll::Ready( None       ) => { self.children.swap_remove( i ); }
+			}
+		}
+
+		None
+	}
+
+
+	/// Whether all tasks that are left are background tasks.
+	//
+	fn only_background( &self ) -> bool
+	{
+		!self.background.is_empty() && self.unordered.len() == self.background.len() && self.children.is_empty()
+	}
+
+
+	/// The number of tasks that can still yield an output, or `None` if more tasks can be spawned.
+	//
+	fn remaining( &self ) -> Option<usize>
+	{
+		if !self.rx_closed || !self.children.is_empty() { return None }
+
+		let waiting = self.ordered.as_ref().map( |r| r.done.len() ).unwrap_or(0);
+
+		Some( self.unordered.iter().filter( |entry| entry.yields_output() ).count() + waiting )
+	}
+
+
+	/// Trip the token and drop all tasks. Returns the ids and names of the tasks dropped. The stream
+	/// will be terminated after this.
+	//
+	fn abort( &mut self, cx: &mut Context<'_> ) -> Vec<(TaskId, Name)>
+	{
+		self.token.cancel();
+		self.drop_tasks( cx )
+	}
+
+
+	/// Keep the payload of a panic, cancel all tasks and end the stream.
+	//
+	fn end_with_panic( &mut self, payload: Payload, cx: &mut Context<'_> ) -> Poll<Option<(TaskId, Name, Out)>>
+	{
+		self.panic = Some( payload );
+		self.abort( cx );
+
+		Poll::Ready( None )
+	}
+
+
+	/// Poll for the next output together with the id of the task that produced it.
+	//
+	pub(crate) fn poll_next_with_id( &mut self, cx: &mut Context<'_> ) -> Poll<Option<(TaskId, Name, Out)>>
+
+		where Out: 'static
+	{
+		// Tasks with a TaskHandle don't yield their output here, so skip over them.
+		//
+		loop
+		{
+			match ready!( self.poll_exit( cx ) )
+			{
+				None => return Poll::Ready( None ),
+
+				Some( (id, name, Exit::Output(out) | Exit::TimedOut(out)) ) => return Poll::Ready( Some( (id, name, out) ) ),
+				Some( (_ , _   , Exit::Detached    | Exit::Cancelled    ) ) => continue,
+
+				Some( (_, _, Exit::Panicked( payload, OnPanic::Propagate      )) ) => std::panic::resume_unwind( payload.into_inner() ),
+				Some( (_, _, Exit::Panicked( payload, OnPanic::CancelSiblings )) ) => return self.end_with_panic( payload, cx ),
+			}
+		}
+	}
+
+
+	/// Poll for the next task that ended, with the outcome as seen by [`Outcomes`].
+	//
+	pub(crate) fn poll_outcome( &mut self, cx: &mut Context<'_> ) -> Poll<Option<(TaskId, Name, TaskOutcome<Out>)>>
+
+		where Out: 'static
+	{
+		loop
+		{
+			let (id, name, exit) = match ready!( self.poll_exit( cx ) )
+			{
+				None       => return Poll::Ready( None ),
+				Some(next) => next,
+			};
+
+			let outcome = match exit
+			{
+				Exit::Output  ( out ) => TaskOutcome::Completed( out ),
+				Exit::TimedOut( _   ) => TaskOutcome::TimedOut        ,
+				Exit::Cancelled       => TaskOutcome::Cancelled       ,
+				Exit::Detached        => continue                     ,
+
+				Exit::Panicked( payload, on_panic ) =>
+				{
+					// The other tasks are reported as cancelled after this one.
+					//
+					if on_panic == OnPanic::CancelSiblings
+					{
+						let dropped = self.abort( cx );
+						self.cancelled.extend( dropped );
+					}
+
+					TaskOutcome::Panicked( payload.into_inner() )
+				}
+			};
+
+			return Poll::Ready( Some( (id, name, outcome) ) )
+		}
+	}
+
+
+	/// Poll for the next task that ended, together with it's id and name. Panics are left to the caller.
+	/// Tasks that the stream dropped itself end as [`Exit::Cancelled`].
+	//
+	fn poll_exit( &mut self, cx: &mut Context<'_> ) -> Poll<Option<(TaskId, Name, Exit<Out>)>>
+
+		where Out: 'static
 	{
 		// Try to get as many JoinHandles as we can to put them in FuturesUnordered.
 		//
 		while !self.rx_closed
 		{
-			match Pin::new( &mut self.as_mut().rx ).poll_next(cx)
+			match self.rx.poll_recv( cx )
 			{
-				Poll::Pending               => break                         ,
-				Poll::Ready( None         ) => self.rx_closed = true         ,
-				Poll::Ready( Some(handle) ) => self.unordered.push( handle ) ,
+				Poll::Pending       => break                 ,
+				Poll::Ready( None ) => self.rx_closed = true ,
+				Poll::Ready( Some( Message::Task(mut entry) ) ) =>
+				{
+					entry.dequeue();
+
+					if !entry.is_critical()
+					{
+						self.background.insert( entry.id() );
+					}
+
+					else if let Some(reorder) = &mut self.ordered
+					{
+						reorder.order.push_back( entry.id() );
+					}
+
+					self.unordered.push( entry );
+				}
+
+				Poll::Ready( Some( Message::Child(mut stream) ) ) =>
+				{
+					// The child was created before we got cancelled.
+					//
+					if self.token.is_cancelled()
+					{
+						stream.cancel();
+					}
+
+					self.children.push( *stream );
+				}
 			}
 		}
 
-		match ready!( Pin::new( &mut self.as_mut().unordered ).poll_next(cx) )
+		loop
 		{
-			None if self.rx_closed => Poll::Ready(None) ,
-			None                   => Poll::Pending     ,
-			out                    => Poll::Ready(out)  ,
+			if let Some( (id, name) ) = self.cancelled.pop_front()
+			{
+				return Poll::Ready( Some( (id, name, Exit::Cancelled) ) )
+			}
+
+			if let Some(next) = self.ordered.as_mut().and_then( Reorder::pop )
+			{
+				return Poll::Ready( Some(next) )
+			}
+
+			if let Some(next) = self.poll_children( cx )
+			{
+				return Poll::Ready( Some(next) )
+			}
+
+			// The critical tasks are done, so we don't wait for the background tasks.
+			//
+			if self.only_background()
+			{
+				#[ cfg( feature = "tracing" ) ]
+				//
+				tracing_crate::debug!( parent: &self.span, "critical tasks done, dropping background tasks" );
+
+				let dropped = self.abort( cx );
+				self.cancelled.extend( dropped );
+
+				continue
+			}
+
+			match ready!( self.unordered.poll_next_unpin( cx ) )
+			{
+				Some( (id, name, exit) ) =>
+				{
+					let background = self.background.remove( &id );
+
+					match ( &mut self.ordered, exit )
+					{
+						( None, exit ) => return Poll::Ready( Some( (id, name, exit) ) ),
+
+						( Some(_), exit ) if background => return Poll::Ready( Some( (id, name, exit) ) ),
+
+						// Panics don't wait for their turn.
+						//
+						( Some(reorder), exit @ Exit::Panicked(..) ) =>
+						{
+							reorder.order.retain( |waiting| *waiting != id );
+
+							return Poll::Ready( Some( (id, name, exit) ) )
+						}
+
+						( Some(reorder), exit ) => { reorder.done.insert( id, (name, exit) ); }
+					}
+				}
+
+				None if self.rx_closed && self.children.is_empty() => return Poll::Ready( None ),
+				None                                               => return Poll::Pending      ,
+			}
 		}
 	}
+}
+
+
+
+impl<T, E> NurseryStream< Result<T, E> >
+{
+	/// Resolve with the first `Ok` output. Errors are collected until then. When a task succeeds,
+	/// the other tasks are dropped just like with [`NurseryStream::race`].
+	///
+	/// Resolves to all errors, in the order they came in, if no task succeeded.
+	//
+	pub fn first_ok( mut self ) -> impl Future< Output = Result< T, Vec<E> > >
+
+		where T: 'static, E: 'static
+	{
+		let mut errors = Vec::new();
+
+		poll_fn( move |cx|
+		{
+			loop
+			{
+				match ready!( self.poll_next_with_id( cx ) )
+				{
+					Some( (_, _, Err(e)) ) => errors.push( e ),
+
+					Some( (_, _, Ok(out)) ) =>
+					{
+						self.abort( cx );
+
+						return Poll::Ready( Ok(out) )
+					}
+
+					None => return Poll::Ready( Err( std::mem::take( &mut errors ) ) ),
+				}
+			}
+		})
+	}
+
+
+	/// Let all tasks run to completion and collect the outputs. Resolves to `Ok` with the outputs if
+	/// all tasks succeeded, otherwise to [`NurseryErrors`] with all errors. As opposed to
+	/// [`TryNurseryStream`](crate::TryNurseryStream), an error doesn't stop the other tasks.
+	//
+	pub fn collect_all( self ) -> impl Future< Output = Result< Vec<T>, NurseryErrors<E> > >
+
+		where T: 'static, E: 'static
+	{
+		self.collect_all_budget( usize::MAX )
+	}
+
+
+	/// Like [`NurseryStream::collect_all`], but `max_errors` is the number of errors that are tolerated.
+	/// Once one more task fails, the other tasks are dropped just like with [`NurseryStream::race`].
+	/// Their ids are available from [`NurseryErrors::dropped`].
+	///
+	/// A budget of zero thus drops the other tasks on the first error. Any error still makes this
+	/// resolve to `Err`, even when the budget was not used up.
+	//
+	pub fn collect_all_budget( mut self, max_errors: usize ) -> impl Future< Output = Result< Vec<T>, NurseryErrors<E> > >
+
+		where T: 'static, E: 'static
+	{
+		let mut outputs = Vec::new();
+		let mut errors  = Vec::new();
+
+		poll_fn( move |cx|
+		{
+			let mut dropped = None;
+
+			loop
+			{
+				if errors.len() > max_errors
+				{
+					dropped = Some( self.abort( cx ) );
+					break
+				}
+
+				match ready!( self.poll_next_with_id( cx ) )
+				{
+					Some( (_ , _   , Ok (out)) ) => outputs.push( out           ),
+					Some( (id, name, Err(e  )) ) => errors .push( (id, name, e) ),
+					None                         => break                        ,
+				}
+			}
+
+			if errors.is_empty() && dropped.is_none()
+			{
+				return Poll::Ready( Ok( std::mem::take( &mut outputs ) ) )
+			}
+
+			Poll::Ready( Err( NurseryErrors::new( std::mem::take( &mut errors ), dropped.unwrap_or_default() ) ) )
+		})
+	}
+
+
+	/// Collect outputs until `n` tasks succeeded, then drop the other tasks just like with
+	/// [`NurseryStream::race`]. It also gives up early once the nursery is closed and the tasks that
+	/// are left can no longer make up `n` successes.
+	///
+	/// Resolves to a [`QuorumReport`] with the successes and the failures observed so far.
+	//
+	pub fn quorum( mut self, n: usize ) -> impl Future< Output = QuorumReport<T, E> >
+
+		where T: 'static, E: 'static
+	{
+		let mut succeeded = Vec::new();
+		let mut failed    = Vec::new();
+
+		poll_fn( move |cx|
+		{
+			loop
+			{
+				if succeeded.len() >= n
+				{
+					self.abort( cx );
+					break
+				}
+
+				// Even when no task is ready, the nursery might just have been closed.
+				//
+				let pending = match self.poll_next_with_id( cx )
+				{
+					Poll::Ready( Some( (_, _, Ok (out)) ) ) => { succeeded.push( out ); continue }
+					Poll::Ready( Some( (_, _, Err(e  )) ) ) => { failed   .push( e   ); false    }
+					Poll::Ready( None                     ) => break                            ,
+					Poll::Pending                           => true                             ,
+				};
+
+				if self.remaining().is_some_and( |left| succeeded.len() + left < n )
+				{
+					self.abort( cx );
+					break
+				}
+
+				if pending { return Poll::Pending }
+			}
+
+			let reached = succeeded.len() >= n;
+
+			Poll::Ready( QuorumReport
+			{
+				succeeded: std::mem::take( &mut succeeded ),
+				failed   : std::mem::take( &mut failed    ),
+				reached                                    ,
+			})
+		})
+	}
+}
+
+
+
+// We never pin project to our fields.
+//
+impl<Out> Unpin for NurseryStream<Out> {}
+
+
+
+impl<Out> Stream for NurseryStream<Out>
+
+	where Out: 'static
+{
+	type Item = Out;
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		self.poll_next_with_id( cx ).map( |next| next.map( |(_, _, out)| out ) )
+	}
 
 
 	/// A hint of the number of tasks currently being awaited. There is no upper bound,
-	/// because we don't keep track of the tasks that are in the channel between the `Nursery` and
-	/// the `NurseryStream`.
+	/// because more tasks can be spawned at any time.
 	//
 	fn size_hint( &self ) -> (usize, Option<usize>)
 	{
-		// UnboundedReceiver does not have a size hint, so we don't know the upper bound
-		// unless we count it ourselves.
-		//
-		(self.unordered.size_hint().0, None)
+		let waiting  = self.ordered.as_ref().map( |r| r.done.len() ).unwrap_or(0);
+		let children = self.children.iter().map( |c| c.size_hint().0 ).sum::<usize>();
+
+		(self.unordered.size_hint().0 + self.cancelled.len() + waiting + children, None)
 	}
 }
 
@@ -125,7 +832,7 @@ impl<Out> FusedFuture for NurseryStream<Out>
 {
 	fn is_terminated(&self) -> bool
 	{
-		self.rx_closed && self.unordered.is_terminated()
+		self.rx_closed && self.unordered.is_terminated() && self.children.is_empty() && self.cancelled.is_empty()
 	}
 }
 
@@ -138,6 +845,184 @@ impl<Out> FusedStream for NurseryStream<Out>
 {
 	fn is_terminated(&self) -> bool
 	{
-		self.rx_closed && self.unordered.is_terminated()
+		self.rx_closed && self.unordered.is_terminated() && self.children.is_empty() && self.cancelled.is_empty()
+	}
+}
+
+
+
+/// Buffers outputs that finished before tasks spawned earlier in ordered mode.
+//
+#[ derive( Debug ) ]
+//
+struct Reorder<Out>
+{
+	/// The ids of the tasks that haven't been yielded yet, in the order they were spawned.
+	//
+	order: VecDeque<TaskId>,
+
+	/// The tasks that finished, but wait for a task that was spawned earlier.
+	//
+	done: HashMap< TaskId, (Name, Exit<Out>) >,
+}
+
+
+
+impl<Out> Default for Reorder<Out>
+{
+	fn default() -> Self
+	{
+		Self{ order: VecDeque::new(), done: HashMap::new() }
+	}
+}
+
+
+
+impl<Out> Reorder<Out>
+{
+	/// The next task in spawn order, if it is finished.
+	//
+	fn pop( &mut self ) -> Option< (TaskId, Name, Exit<Out>) >
+	{
+		let id           = *self.order.front()?;
+		let (name, exit) = self.done.remove( &id )?;
+
+		self.order.pop_front();
+
+		Some( (id, name, exit) )
+	}
+
+
+	/// Take out all outputs of finished tasks, in spawn order.
+	//
+	fn take_finished( &mut self ) -> Vec<(TaskId, Name, Out)>
+	{
+		let done = &mut self.done;
+
+		self.order.iter().filter_map( |id| match done.remove( id )?
+		{
+			(name, Exit::Output(out) | Exit::TimedOut(out)) => Some( (*id, name, out) ),
+			_                                               => None                   ,
+		})
+		.collect()
+	}
+}
+
+
+
+/// The result of [`NurseryStream::shutdown`].
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct ShutdownReport<Out>
+{
+	/// The ids and outputs of the tasks that finished within the grace period. Tasks spawned with
+	/// a [`TaskHandle`](crate::TaskHandle) deliver their output to the handle, so they don't appear here.
+	//
+	pub finished: Vec<(TaskId, Out)>,
+
+	/// The ids of the tasks that were still running when the grace period expired and were dropped.
+	//
+	pub dropped: Vec<TaskId>,
+
+	/// The names of the tasks in `finished` and `dropped` that were given one with [`TaskBuilder::name`](crate::TaskBuilder::name).
+	//
+	pub names: HashMap< TaskId, Arc<str> >,
+}
+
+
+
+impl<Out> ShutdownReport<Out>
+{
+	/// The name of the task with this id, if it has one.
+	//
+	pub fn name( &self, id: TaskId ) -> Option<&str>
+	{
+		self.names.get( &id ).map( |name| &**name )
+	}
+}
+
+
+
+/// The result of [`NurseryStream::quorum`].
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct QuorumReport<T, E>
+{
+	/// The outputs of the tasks that succeeded, in the order they finished.
+	//
+	pub succeeded: Vec<T>,
+
+	/// The errors of the tasks that failed before the quorum was decided, in the order they finished.
+	//
+	pub failed: Vec<E>,
+
+	/// Whether the requested number of tasks succeeded.
+	//
+	pub reached: bool,
+}
+
+
+
+/// Stream adapter returned by [`NurseryStream::with_ids`]. Yields the id of each task together
+/// with it's output.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+#[ must_use = "streams do nothing unless polled" ]
+//
+pub struct WithIds<Out>
+{
+	inner: NurseryStream<Out>,
+}
+
+
+
+impl<Out> WithIds<Out>
+{
+	/// Get back the wrapped `NurseryStream`.
+	//
+	pub fn into_inner( self ) -> NurseryStream<Out>
+	{
+		self.inner
+	}
+}
+
+
+
+impl<Out> Stream for WithIds<Out>
+
+	where Out: 'static
+{
+	type Item = (TaskId, Out);
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		self.inner.poll_next_with_id( cx ).map( |next| next.map( |(id, _, out)| (id, out) ) )
+	}
+
+
+	fn size_hint( &self ) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+
+
+impl<Out> FusedStream for WithIds<Out>
+
+	where Out: 'static
+{
+	fn is_terminated( &self ) -> bool
+	{
+		FusedStream::is_terminated( &self.inner )
 	}
 }
@@ -0,0 +1,136 @@
+use crate:: { import::*, TaskId };
+use std  :: { time::Instant };
+
+
+/// Receives events about the tasks of a nursery, so you can record metrics. Set it with
+/// [`Nursery::set_observer`](crate::Nursery::set_observer). To tell nurseries apart, give each
+/// one it's own observer, eg. one that carries a label.
+///
+/// All methods have an empty default implementation, so you only implement what you need. They are
+/// called from within the tasks and the [`NurseryStream`](crate::NurseryStream), so they should be
+/// cheap, like incrementing a counter or recording in a histogram.
+///
+/// Durations are measured with [`std::time::Instant`], which is not available on `wasm32-unknown-unknown`.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "metrics" )) ) ]
+//
+pub trait NurseryObserver: Send + Sync
+{
+	/// A task was spawned on the nursery.
+	//
+	fn on_spawn( &self, _id: TaskId ) {}
+
+	/// A task was polled, which took `duration`. Long polls block the thread of the executor.
+	//
+	fn on_poll( &self, _duration: Duration ) {}
+
+	/// A task ended. `latency` is the time since it was spawned.
+	//
+	fn on_end( &self, _id: TaskId, _end: TaskEnd, _latency: Duration ) {}
+}
+
+
+
+/// How a task ended, as reported to [`NurseryObserver::on_end`].
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "metrics" )) ) ]
+//
+#[ derive( Clone, Copy, PartialEq, Eq, Debug ) ]
+//
+pub enum TaskEnd
+{
+	/// The task finished. This includes tasks that returned an error, eg. [`NurseErr::Timeout`](crate::NurseErr::Timeout).
+	//
+	Completed,
+
+	/// The task panicked. Only reported when a [`PanicPolicy`](crate::PanicPolicy) is set on the nursery,
+	/// otherwise it depends on the executor what happens. With [`PanicPolicy::Map`](crate::PanicPolicy::Map),
+	/// the task completes with the mapped output, so it is reported as `Completed`.
+	//
+	Panicked,
+
+	/// The task was dropped before it finished, eg. because the `NurseryStream` was dropped.
+	//
+	Dropped,
+}
+
+
+
+/// The observer of a nursery, shared by all it's clones.
+//
+#[ derive( Clone ) ]
+//
+pub(crate) struct Observer( Arc<dyn NurseryObserver> );
+
+
+
+impl Observer
+{
+	pub(crate) fn new( observer: Arc<dyn NurseryObserver> ) -> Self
+	{
+		Self( observer )
+	}
+
+
+	/// Report the duration of every poll of `fut`.
+	//
+	pub(crate) fn wrap<F>( &self, mut fut: F ) -> impl Future<Output = F::Output>
+
+		where F: Future + Unpin
+	{
+		let observer = self.0.clone();
+
+		poll_fn( move |cx|
+		{
+			let start = Instant::now();
+			let out   = fut.poll_unpin( cx );
+
+			observer.on_poll( start.elapsed() );
+
+			out
+		})
+	}
+
+
+	/// Report that a task was spawned. The returned value reports when it ends.
+	//
+	pub(crate) fn spawned( &self, id: TaskId ) -> Observed
+	{
+		self.0.on_spawn( id );
+
+		Observed{ observer: self.clone(), id, spawned: Instant::now() }
+	}
+}
+
+
+
+impl std::fmt::Debug for Observer
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		f.debug_tuple( "Observer" ).finish_non_exhaustive()
+	}
+}
+
+
+
+/// A task that is being observed.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Observed
+{
+	observer: Observer ,
+	id      : TaskId   ,
+	spawned : Instant  ,
+}
+
+
+
+impl Observed
+{
+	pub(crate) fn end( self, end: TaskEnd )
+	{
+		self.observer.0.on_end( self.id, end, self.spawned.elapsed() );
+	}
+}
@@ -0,0 +1,217 @@
+use crate:: { import::*, bound::Permit };
+use std  :: { any::Any   };
+
+
+/// What to do when a task panics. Set it with [`Nursery::set_panic_policy`](crate::Nursery::set_panic_policy).
+///
+/// When a policy is set, every task spawned on the nursery is wrapped in `catch_unwind`, so the
+/// behavior is the same on all executors. Without a policy, it depends on the executor what happens
+/// when a task panics.
+///
+/// Note that on wasm, panics abort, so there is nothing to catch.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+pub enum PanicPolicy<Out>
+{
+	/// Resume the panic on the thread that polls the [`NurseryStream`](crate::NurseryStream).
+	//
+	Propagate,
+
+	/// Convert the panic payload into an output for the task.
+	//
+	Map( Arc< dyn Fn( Box<dyn Any + Send> ) -> Out + Send + Sync > ),
+
+	/// Trip the [`CancelToken`](crate::CancelToken), drop all other tasks and end the `NurseryStream`.
+	/// The panic payload can be retrieved with [`NurseryStream::take_panic`](crate::NurseryStream::take_panic).
+	//
+	CancelSiblings,
+}
+
+
+
+impl<Out> PanicPolicy<Out>
+{
+	/// Create a [`PanicPolicy::Map`] from a closure.
+	//
+	pub fn map( f: impl Fn( Box<dyn Any + Send> ) -> Out + Send + Sync + 'static ) -> Self
+	{
+		PanicPolicy::Map( Arc::new(f) )
+	}
+
+
+	/// Wrap a future so that panics are handled according to this policy. Unless the policy is `Map`,
+	/// a [`PanicSlot`] is returned that the `NurseryStream` must watch for the panic.
+	///
+	/// The permit of a bounded nursery is held until the future finishes. When it panics, the permit is
+	/// released before waiting for the `NurseryStream` to drop the task.
+	//
+	pub(crate) fn wrap<F>( &self, fut: F, permit: Option<Permit> ) -> ( impl Future<Output = Out>, Option<Arc<PanicSlot>> )
+
+		where F: Future<Output = Out>
+	{
+		let (catch, slot) = match self
+		{
+			PanicPolicy::Map( map ) => ( Catch::Map( map.clone() ), None ),
+
+			PanicPolicy::Propagate =>
+			{
+				let slot = Arc::new( PanicSlot::new( OnPanic::Propagate ) );
+				( Catch::Slot( slot.clone() ), Some(slot) )
+			}
+
+			PanicPolicy::CancelSiblings =>
+			{
+				let slot = Arc::new( PanicSlot::new( OnPanic::CancelSiblings ) );
+				( Catch::Slot( slot.clone() ), Some(slot) )
+			}
+		};
+
+		let fut = async move
+		{
+			match AssertUnwindSafe( fut ).catch_unwind().await
+			{
+				Ok(out) => out,
+
+				Err(payload) =>
+				{
+					drop( permit );
+
+					match catch
+					{
+						Catch::Map ( map  ) => map( payload ),
+						Catch::Slot( slot ) => { slot.store( payload ); pending().await }
+					}
+				}
+			}
+		};
+
+		(fut, slot)
+	}
+}
+
+
+
+/// Wrap a blocking closure so panics are caught on the blocking thread, as executors don't agree on
+/// what happens otherwise. With `Map`, the payload is mapped right away. Otherwise it is returned for
+/// the `NurseryStream` to handle as told by the returned [`OnPanic`].
+//
+pub(crate) fn wrap_blocking<Out, F>( policy: Option<&PanicPolicy<Out>>, f: F ) -> ( impl FnOnce() -> std::thread::Result<Out>, OnPanic )
+
+	where F: FnOnce() -> Out
+{
+	let (map, on_panic) = match policy
+	{
+		Some( PanicPolicy::Map( map )     ) => ( Some( map.clone() ), OnPanic::Propagate      ),
+		Some( PanicPolicy::CancelSiblings ) => ( None               , OnPanic::CancelSiblings ),
+		Some( PanicPolicy::Propagate      ) |
+		None                                => ( None               , OnPanic::Propagate      ),
+	};
+
+	let f = move || match ( std::panic::catch_unwind( AssertUnwindSafe(f) ), map )
+	{
+		( Err(payload), Some(map) ) => Ok( map( payload ) ),
+		( out         , _         ) => out,
+	};
+
+	(f, on_panic)
+}
+
+
+
+impl<Out> Clone for PanicPolicy<Out>
+{
+	fn clone( &self ) -> Self
+	{
+		match self
+		{
+			PanicPolicy::Propagate      => PanicPolicy::Propagate,
+			PanicPolicy::Map( map )     => PanicPolicy::Map( map.clone() ),
+			PanicPolicy::CancelSiblings => PanicPolicy::CancelSiblings,
+		}
+	}
+}
+
+
+
+impl<Out> std::fmt::Debug for PanicPolicy<Out>
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		match self
+		{
+			PanicPolicy::Propagate      => write!( f, "PanicPolicy::Propagate"      ),
+			PanicPolicy::Map(_)         => write!( f, "PanicPolicy::Map"            ),
+			PanicPolicy::CancelSiblings => write!( f, "PanicPolicy::CancelSiblings" ),
+		}
+	}
+}
+
+
+
+/// How a task deals with a panic it caught.
+//
+enum Catch<Out>
+{
+	Map ( Arc< dyn Fn( Box<dyn Any + Send> ) -> Out + Send + Sync > ),
+	Slot( Arc<PanicSlot>                                            ),
+}
+
+
+
+/// What the `NurseryStream` does with a panic caught in a task.
+//
+#[ derive( Clone, Copy, PartialEq, Eq, Debug ) ]
+//
+pub(crate) enum OnPanic
+{
+	Propagate,
+	CancelSiblings,
+}
+
+
+
+/// Where a task stores the payload of a panic, for the `NurseryStream` to pick up.
+/// After storing it, the task never finishes, so the `NurseryStream` will drop it.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct PanicSlot
+{
+	on_panic: OnPanic                              ,
+	payload : Mutex< Option<Box<dyn Any + Send>> > ,
+	waker   : AtomicWaker                          ,
+}
+
+
+
+impl PanicSlot
+{
+	fn new( on_panic: OnPanic ) -> Self
+	{
+		Self
+		{
+			on_panic                    ,
+			payload: Mutex::new( None ) ,
+			waker  : AtomicWaker::new() ,
+		}
+	}
+
+
+	fn store( &self, payload: Box<dyn Any + Send> )
+	{
+		*self.payload.lock().unwrap_or_else( PoisonError::into_inner ) = Some( payload );
+
+		self.waker.wake();
+	}
+
+
+	/// Take the panic payload if the task panicked, otherwise register to be woken up when it does.
+	//
+	pub(crate) fn poll_take( &self, cx: &mut Context<'_> ) -> Option< (Box<dyn Any + Send>, OnPanic) >
+	{
+		self.waker.register( cx.waker() );
+
+		self.payload.lock().unwrap_or_else( PoisonError::into_inner ).take().map( |p| (p, self.on_panic) )
+	}
+}
@@ -0,0 +1,239 @@
+use crate:: { import::*, Nurse, LocalNurse, NurseErr, Nursery, CancelToken };
+
+
+/// Run a scope in which tasks can borrow from the enclosing stack frame. The closure receives a
+/// [`Scope`] handle and returns the body of the scope. Resolves to the output of the body together
+/// with the outputs of all tasks spawned on the scope, once the body and all tasks are done.
+///
+/// Futures passed to [`Scope::nurse_scoped`] can borrow anything that outlives the call to `scope`.
+/// They run concurrently, but not in parallel, as they are polled by the future returned from `scope`
+/// rather than spawned on the executor. Through the [`Nurse`] and [`LocalNurse`] impls, the scope
+/// spawns `'static` futures on the executor like a [`Nursery`].
+///
+/// This is safe because nothing borrowed ever leaves the future returned by `scope`. If that future is
+/// dropped, so are all tasks. Once it has resolved, spawning on the scope returns [`NurseErr::Closed`].
+///
+/// ```rust, ignore
+/// let data = vec![ 1, 2, 3 ];
+/// let data = &data;
+///
+/// let ((), outputs) = async_nursery::scope( AsyncStd, |s| async move
+/// {
+///    for x in data
+///    {
+///       s.nurse_scoped( async move { x * 2 } ).expect( "nurse" );
+///    }
+/// }).await;
+/// ```
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+pub async fn scope<'env, S, Out, F, Fut>( spawner: S, f: F ) -> ( Fut::Output, Vec<Out> )
+
+	where F  : FnOnce( Scope<'env, S, Out> ) -> Fut ,
+	      Fut: Future + 'env                        ,
+	      Out: 'static                              ,
+{
+	let (nursery, mut stream) = Nursery::new( spawner );
+	let incoming              = Arc::new( Incoming::default() );
+
+	let body = f( Scope{ nursery, incoming: incoming.clone() } );
+	futures::pin_mut!( body );
+
+	let mut out      = None;
+	let mut outputs  = Vec::new();
+	let mut borrowed = FuturesUnordered::new();
+	let mut idle     = false;
+
+	poll_fn( move |cx|
+	{
+		if out.is_none()
+		{
+			if let Poll::Ready(o) = body.as_mut().poll( cx )
+			{
+				out = Some(o);
+			}
+		}
+
+		incoming.waker.register( cx.waker() );
+
+		loop
+		{
+			borrowed.extend( incoming.take() );
+
+			match borrowed.poll_next_unpin( cx )
+			{
+				Poll::Ready( Some(o) ) => outputs.push( o ),
+				_                      => break,
+			}
+		}
+
+		while !idle
+		{
+			match stream.poll_next_unpin( cx )
+			{
+				Poll::Ready( Some(o) ) => outputs.push( o ),
+				Poll::Ready( None    ) => idle = true,
+				Poll::Pending          => break,
+			}
+		}
+
+		// Tasks can only be spawned by the body or by other tasks, so if all of them are done,
+		// nothing can be spawned anymore.
+		//
+		if borrowed.is_empty() && incoming.is_empty() && ( idle || stream.is_idle() )
+		{
+			if let Some(out) = out.take()
+			{
+				incoming.close();
+				stream.close_nursery();
+
+				return Poll::Ready( (out, std::mem::take( &mut outputs )) )
+			}
+		}
+
+		Poll::Pending
+	}).await
+}
+
+
+
+/// The handle to a [`scope`]. Spawn futures that borrow from the enclosing stack frame with
+/// [`Scope::nurse_scoped`], or `'static` futures on the executor with [`Nurse`] and [`LocalNurse`].
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct Scope<'env, S, Out>
+{
+	nursery : Nursery<S, Out>             ,
+	incoming: Arc< Incoming<'env, Out> >  ,
+}
+
+
+
+impl<'env, S, Out> Scope<'env, S, Out>
+{
+	/// Run a future that can borrow from the stack frame enclosing the scope. It is polled by the
+	/// scope, so it does not run in parallel with the scope.
+	//
+	pub fn nurse_scoped( &self, fut: impl Future<Output = Out> + Send + 'env ) -> Result<(), NurseErr>
+	{
+		self.incoming.push( Box::pin(fut) )
+	}
+
+
+	/// Get the [`CancelToken`] of this scope.
+	//
+	pub fn cancel_token( &self ) -> CancelToken
+	{
+		self.nursery.cancel_token()
+	}
+}
+
+
+
+impl<S, Out> Clone for Scope<'_, S, Out> where S: Clone
+{
+	fn clone( &self ) -> Self
+	{
+		Self
+		{
+			nursery : self.nursery .clone() ,
+			incoming: self.incoming.clone() ,
+		}
+	}
+}
+
+
+
+impl<S, Out> Nurse<Out> for Scope<'_, S, Out> where Nursery<S, Out>: Nurse<Out>, Out: 'static + Send
+{
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	fn nurse_obj( &self, fut: FutureObj<'static, Out> ) -> Result<(), NurseErr>
+	{
+		self.nursery.nurse_obj( fut )
+	}
+}
+
+
+
+impl<S, Out> LocalNurse<Out> for Scope<'_, S, Out> where Nursery<S, Out>: LocalNurse<Out>, Out: 'static
+{
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	fn nurse_local_obj( &self, fut: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
+	{
+		self.nursery.nurse_local_obj( fut )
+	}
+}
+
+
+
+/// Futures passed to [`Scope::nurse_scoped`] that the scope hasn't picked up yet. The scope future
+/// owns the tasks, so tasks can spawn while being polled. `None` once the scope has ended.
+//
+struct Incoming<'env, Out>
+{
+	tasks: Mutex< Option< Vec<BoxFuture<'env, Out>> > > ,
+	waker: AtomicWaker                                  ,
+}
+
+
+
+impl<Out> Default for Incoming<'_, Out>
+{
+	fn default() -> Self
+	{
+		Self{ tasks: Mutex::new( Some( Vec::new() ) ), waker: AtomicWaker::new() }
+	}
+}
+
+
+
+impl<'env, Out> Incoming<'env, Out>
+{
+	fn lock( &self ) -> MutexGuard<'_, Option< Vec<BoxFuture<'env, Out>> >>
+	{
+		self.tasks.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+
+
+	fn push( &self, fut: BoxFuture<'env, Out> ) -> Result<(), NurseErr>
+	{
+		self.lock().as_mut().ok_or( NurseErr::Closed )?.push( fut );
+		self.waker.wake();
+
+		Ok(())
+	}
+
+
+	fn take( &self ) -> Vec<BoxFuture<'env, Out>>
+	{
+		self.lock().as_mut().map( std::mem::take ).unwrap_or_default()
+	}
+
+
+	fn is_empty( &self ) -> bool
+	{
+		self.lock().as_ref().is_none_or( Vec::is_empty )
+	}
+
+
+	fn close( &self )
+	{
+		*self.lock() = None;
+	}
+}
+
+
+
+impl<Out> std::fmt::Debug for Incoming<'_, Out>
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		f.debug_struct( "Incoming" ).finish_non_exhaustive()
+	}
+}
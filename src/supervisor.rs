@@ -0,0 +1,476 @@
+use crate:: { import::*, Nursery, NurseryStream, NurseErr, CancelToken, TaskId, deadline::Deadline };
+use std  :: { any::Any };
+
+
+/// Restarts tasks that fail, built on a [`Nursery`]. Each supervised task is created by a factory,
+/// which is called again to restart the task when it returns an error or panics. A task that
+/// returns `Ok(())` is done and is not restarted.
+///
+/// The [`RestartPolicy`] decides whether only the failed task is restarted or all of them, how many
+/// restarts are allowed within a window of time and how long to wait before restarting. The delays
+/// use the [`Timer`] of the executor.
+///
+/// When the restart limit is reached, the supervisor gives up: the task that failed yields it's
+/// [`Failure`] on the `NurseryStream`, the other tasks are dropped and yield [`Failure::Stopped`], and
+/// no more tasks can be supervised. The same happens to all tasks when the nursery is
+/// [cancelled](NurseryStream::cancel).
+///
+/// ```rust, ignore
+/// let (supervisor, output) = Supervisor::new( AsyncStd, RestartPolicy::one_for_one() );
+///
+/// supervisor.supervise( move || connection_worker( addr ) )?;
+/// ```
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct Supervisor<S, E>
+{
+	nursery: Nursery< S, Result<(), Failure<E>> > ,
+	shared : Arc< Shared<S> >                     ,
+}
+
+
+
+impl<S, E> Supervisor<S, E>
+{
+	/// Create a new supervisor. The stream yields `Ok(())` for tasks that finished and the
+	/// [`Failure`] of tasks that are no longer restarted.
+	//
+	pub fn new( spawner: S, policy: RestartPolicy ) -> ( Self, NurseryStream< Result<(), Failure<E>> > )
+
+		where S: Clone, E: 'static
+	{
+		let (nursery, stream) = Nursery::new( spawner.clone() );
+		let token             = nursery.cancel_token();
+
+		let shared = Arc::new( Shared
+		{
+			timer     : spawner                                          ,
+			policy                                                       ,
+			generation: Mutex::new( token.child_token() )                ,
+			budget    : Mutex::new( Budget{ restarts: 0, window: None } ) ,
+			gave_up   : AtomicBool::new( false )                         ,
+			token                                                        ,
+		});
+
+		( Self{ nursery, shared }, stream )
+	}
+
+
+	/// Supervise a task. `factory` is called to start the task and again every time it is restarted.
+	/// Returns [`NurseErr::Closed`] once the supervisor has given up.
+	//
+	pub fn supervise<F, Fut>( &self, factory: F ) -> Result<TaskId, NurseErr>
+
+		where S  : SpawnHandle< Result<(), Failure<E>> > + Timer + Send + Sync + 'static ,
+		      F  : Fn() -> Fut + Send + 'static                                           ,
+		      Fut: Future< Output = Result<(), E> > + Send + 'static                      ,
+		      E  : Send + 'static                                                         ,
+	{
+		if self.shared.gave_up.load( Ordering::SeqCst ) { return Err( NurseErr::Closed ) }
+
+		self.nursery.nurse_with_id( supervise( self.shared.clone(), factory ) )
+	}
+
+
+	/// Get the [`CancelToken`] of the nursery.
+	//
+	pub fn cancel_token( &self ) -> CancelToken
+	{
+		self.nursery.cancel_token()
+	}
+
+
+	/// Stop accepting tasks. Running tasks are still restarted. See [`Nursery::close_nursery`].
+	//
+	pub fn close( &self )
+	{
+		self.nursery.close_nursery();
+	}
+}
+
+
+
+impl<S, E> Clone for Supervisor<S, E> where S: Clone
+{
+	fn clone( &self ) -> Self
+	{
+		Self
+		{
+			nursery: self.nursery.clone() ,
+			shared : self.shared .clone() ,
+		}
+	}
+}
+
+
+
+/// Run a task, restarting it according to the policy until it finishes or we give up.
+//
+async fn supervise<S, E, F, Fut>( shared: Arc<Shared<S>>, factory: F ) -> Result<(), Failure<E>>
+
+	where S  : Timer                                   ,
+	      F  : Fn() -> Fut                             ,
+	      Fut: Future< Output = Result<(), E> >        ,
+{
+	loop
+	{
+		let generation = shared.generation();
+
+		// The factory is called inside of catch_unwind, so it can panic without killing the task.
+		//
+		let run = match std::panic::catch_unwind( AssertUnwindSafe( &factory ) )
+		{
+			Ok ( fut     ) => Either::Left ( AssertUnwindSafe( fut ).catch_unwind()    ) ,
+			Err( payload ) => Either::Right( futures::future::ready( Err( payload ) ) ) ,
+		};
+
+		futures::pin_mut!( run );
+
+		let failure = match select( run, generation.cancelled() ).await
+		{
+			Either::Left( (Ok ( Ok(()) ), _) ) => return Ok(())            ,
+			Either::Left( (Ok ( Err(e) ), _) ) => Failure::Error   ( e       ),
+			Either::Left( (Err( payload), _) ) => Failure::Panicked( payload ),
+
+			// Another task failed. Either we give up, or all tasks are restarted.
+			//
+			Either::Right(_) =>
+			{
+				if shared.stopped() { return Err( Failure::Stopped ) }
+
+				shared.timer.sleep( shared.delay() ).await;
+				continue;
+			}
+		};
+
+		if shared.stopped() { return Err( Failure::Stopped ) }
+
+		match shared.restart()
+		{
+			Some(delay) => shared.timer.sleep( delay ).await,
+			None        => return Err( failure ),
+		}
+	}
+}
+
+
+
+/// State shared by a supervisor and it's tasks.
+//
+struct Shared<S>
+{
+	timer     : S                  ,
+	policy    : RestartPolicy      ,
+	token     : CancelToken        ,
+	budget    : Mutex<Budget>      ,
+	gave_up   : AtomicBool         ,
+
+	// Cancelled to make the tasks that are running restart, with one-for-all, or stop when we give up.
+	//
+	generation: Mutex<CancelToken> ,
+}
+
+
+
+/// The restarts within the current window.
+//
+struct Budget
+{
+	restarts: usize                ,
+	window  : Option<Deadline<()>> ,
+}
+
+
+
+impl<S> Shared<S> where S: Timer
+{
+	fn generation( &self ) -> CancelToken
+	{
+		self.generation.lock().unwrap_or_else( PoisonError::into_inner ).clone()
+	}
+
+
+	/// Cancel the running tasks and start a new generation.
+	//
+	fn next_generation( &self )
+	{
+		let mut generation = self.generation.lock().unwrap_or_else( PoisonError::into_inner );
+		let old            = std::mem::replace( &mut *generation, self.token.child_token() );
+
+		drop( generation );
+		old.cancel();
+	}
+
+
+	/// Whether tasks should stop rather than restart.
+	//
+	fn stopped( &self ) -> bool
+	{
+		self.gave_up.load( Ordering::SeqCst ) || self.token.is_cancelled()
+	}
+
+
+	/// Count a restart and return how long to wait before restarting. Returns `None` and stops all
+	/// tasks if the limit is reached.
+	//
+	fn restart( &self ) -> Option<Duration>
+	{
+		let mut budget = self.budget.lock().unwrap_or_else( PoisonError::into_inner );
+
+		if budget.window.as_ref().is_some_and( Deadline::has_passed )
+		{
+			budget.window   = None;
+			budget.restarts = 0;
+		}
+
+		if budget.restarts >= self.policy.max_restarts
+		{
+			drop( budget );
+
+			self.gave_up.store( true, Ordering::SeqCst );
+			self.next_generation();
+
+			return None
+		}
+
+		if budget.window.is_none()
+		{
+			budget.window = Some( Deadline::new( self.timer.sleep( self.policy.window ), || () ) );
+		}
+
+		budget.restarts += 1;
+
+		let delay = self.policy.backoff_for( budget.restarts );
+		drop( budget );
+
+		if self.policy.strategy == Strategy::OneForAll
+		{
+			self.next_generation();
+		}
+
+		Some( delay )
+	}
+
+
+	/// The delay for the restarts counted so far.
+	//
+	fn delay( &self ) -> Duration
+	{
+		let restarts = self.budget.lock().unwrap_or_else( PoisonError::into_inner ).restarts;
+
+		self.policy.backoff_for( restarts )
+	}
+}
+
+
+
+impl<S> std::fmt::Debug for Shared<S>
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		f.debug_struct( "Shared" )
+
+			.field( "policy" , &self.policy  )
+			.field( "gave_up", &self.gave_up )
+			.finish_non_exhaustive()
+	}
+}
+
+
+
+/// Which tasks a [`Supervisor`] restarts when one fails.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Clone, Copy, PartialEq, Eq, Debug ) ]
+//
+pub enum Strategy
+{
+	/// Only restart the task that failed.
+	//
+	OneForOne,
+
+	/// Drop and restart all running tasks when one fails.
+	//
+	OneForAll,
+}
+
+
+
+/// When and how a [`Supervisor`] restarts tasks.
+///
+/// By default, a policy allows 3 restarts within 5 seconds, waiting 10ms before the first restart in
+/// a window and doubling the delay for every further restart, up to 1 second.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Clone, Copy, Debug ) ]
+//
+pub struct RestartPolicy
+{
+	strategy    : Strategy ,
+	max_restarts: usize    ,
+	window      : Duration ,
+	backoff     : Duration ,
+	max_backoff : Duration ,
+}
+
+
+
+impl RestartPolicy
+{
+	/// Only restart the task that failed.
+	//
+	pub fn one_for_one() -> Self
+	{
+		Self::new( Strategy::OneForOne )
+	}
+
+
+	/// Restart all running tasks when one fails.
+	//
+	pub fn one_for_all() -> Self
+	{
+		Self::new( Strategy::OneForAll )
+	}
+
+
+	fn new( strategy: Strategy ) -> Self
+	{
+		Self
+		{
+			strategy                              ,
+			max_restarts: 3                       ,
+			window      : Duration::from_secs  (5 ) ,
+			backoff     : Duration::from_millis(10) ,
+			max_backoff : Duration::from_secs  (1 ) ,
+		}
+	}
+
+
+	/// Give up when more than `max_restarts` are needed within `window`. The window starts with
+	/// the first restart. After it, counting starts over.
+	//
+	pub fn max_restarts( mut self, max_restarts: usize, window: Duration ) -> Self
+	{
+		self.max_restarts = max_restarts;
+		self.window       = window;
+		self
+	}
+
+
+	/// Wait `initial` before the first restart within a window, doubling the delay with every
+	/// further restart, up to `max`.
+	//
+	pub fn backoff( mut self, initial: Duration, max: Duration ) -> Self
+	{
+		self.backoff     = initial;
+		self.max_backoff = max;
+		self
+	}
+
+
+	/// The strategy of this policy.
+	//
+	pub fn strategy( &self ) -> Strategy
+	{
+		self.strategy
+	}
+
+
+	/// The delay before the given restart, counting from 1.
+	//
+	fn backoff_for( &self, restart: usize ) -> Duration
+	{
+		let doublings = u32::try_from( restart.saturating_sub(1) ).unwrap_or( u32::MAX ).min( 31 );
+
+		self.backoff.saturating_mul( 1 << doublings ).min( self.max_backoff )
+	}
+}
+
+
+
+impl Default for RestartPolicy
+{
+	fn default() -> Self
+	{
+		Self::one_for_one()
+	}
+}
+
+
+
+/// Why a task of a [`Supervisor`] is no longer restarted.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub enum Failure<E>
+{
+	/// The task returned this error when the restart limit was reached.
+	//
+	Error( E ),
+
+	/// The task panicked when the restart limit was reached.
+	//
+	Panicked( Box<dyn Any + Send> ),
+
+	/// The task was dropped because the supervisor gave up, or the nursery was cancelled.
+	//
+	Stopped,
+}
+
+
+
+impl<E> std::fmt::Display for Failure<E>
+
+	where E: std::fmt::Display
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		match self
+		{
+			Failure::Error( e ) =>
+
+				write!( f, "The supervised task failed: {e}" ),
+
+			Failure::Panicked( payload ) =>
+			{
+				let msg = payload.downcast_ref::<&str>().copied()
+					.or_else( || payload.downcast_ref::<String>().map( String::as_str ) );
+
+				match msg
+				{
+					Some( msg ) => write!( f, "The supervised task panicked: {msg}" ),
+					None        => write!( f, "The supervised task panicked."       ),
+				}
+			}
+
+			Failure::Stopped =>
+
+				write!( f, "The supervised task was stopped." ),
+		}
+	}
+}
+
+
+
+impl<E> std::error::Error for Failure<E>
+
+	where E: std::error::Error + 'static
+{
+	/// The error of the task, if it returned one.
+	//
+	fn source( &self ) -> Option<&(dyn std::error::Error + 'static)>
+	{
+		match self
+		{
+			Failure::Error( e ) => Some( e ),
+			_                   => None    ,
+		}
+	}
+}
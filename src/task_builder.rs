@@ -0,0 +1,171 @@
+use crate:: { import::*, CancelToken, Nursery, NurseErr, TaskId, deadline::{ Deadline, TimedOut }, entry::Name };
+
+
+/// Spawn a task with options on a [`Nursery`]. Get one from [`Nursery::task`].
+///
+/// ```rust, ignore
+/// let id = nursery.task()
+///
+///    .name   ( "fetch config"          )
+///    .timeout( Duration::from_secs(5) )
+///    .spawn  ( fetch_config()          )?
+/// ;
+/// ```
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+#[ must_use = "The task is only spawned when calling spawn." ]
+//
+pub struct TaskBuilder<'a, S, Out>
+{
+	nursery : &'a Nursery<S, Out>     ,
+	meta    : TaskMeta                ,
+	deadline: Option< Deadline<Out> > ,
+}
+
+
+
+impl<'a, S, Out> TaskBuilder<'a, S, Out>
+{
+	pub(crate) fn new( nursery: &'a Nursery<S, Out> ) -> Self
+	{
+		Self{ nursery, meta: TaskMeta::default(), deadline: None }
+	}
+
+
+	/// Give the task a name. It is reported by [`NurseryErrors`](crate::NurseryErrors), [`ShutdownReport`](crate::ShutdownReport),
+	/// [`Outcomes`](crate::Outcomes) and [`NurseryStream::running_tasks`](crate::NurseryStream::running_tasks).
+	/// With the `tracing` feature, it is also recorded in the span of the task.
+	//
+	pub fn name( mut self, name: impl Into<String> ) -> Self
+	{
+		self.meta.name = Some( Arc::from( name.into() ) );
+		self
+	}
+
+
+	/// Whether the task is critical. Tasks are critical by default. The [`NurseryStream`](crate::NurseryStream)
+	/// doesn't wait for background tasks, eg. a heartbeat loop: once only background tasks are left, it drops
+	/// them and ends. With the `tracing` feature, it is recorded in the span of the task.
+	//
+	pub fn critical( mut self, critical: bool ) -> Self
+	{
+		self.meta.critical = critical;
+		self
+	}
+
+
+	/// Spawn the task. Returns the [`TaskId`] assigned to it.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn spawn( mut self, fut: impl Future<Output = Out> + Send + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnHandle<Out>, Out: 'static + Send
+	{
+		let fut = match &self.deadline
+		{
+			None           => FutureObj::new( Box::new( fut ) ),
+			Some(deadline) =>
+			{
+				let timed_out = TimedOut::default();
+				self.meta.timed_out = Some( timed_out.clone() );
+
+				FutureObj::new( Box::new( deadline.wrap( fut, timed_out ) ) )
+			}
+		};
+
+		self.nursery.nurse_meta( fut, &self.meta )
+	}
+
+
+	/// Spawn a `!Send` task. Returns the [`TaskId`] assigned to it.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn spawn_local( mut self, fut: impl Future<Output = Out> + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: LocalSpawnHandle<Out>, Out: 'static
+	{
+		let fut = match &self.deadline
+		{
+			None           => LocalFutureObj::new( Box::new( fut ) ),
+			Some(deadline) =>
+			{
+				let timed_out = TimedOut::default();
+				self.meta.timed_out = Some( timed_out.clone() );
+
+				LocalFutureObj::new( Box::new( deadline.wrap( fut, timed_out ) ) )
+			}
+		};
+
+		self.nursery.nurse_local_meta( fut, &self.meta )
+	}
+
+
+	/// Run a blocking closure, see [`Nursery::nurse_blocking`]. Returns the [`TaskId`] assigned to it.
+	/// Blocking tasks can't be dropped, so a [timeout](TaskBuilder::timeout) doesn't apply to them.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn spawn_blocking( self, f: impl FnOnce() -> Out + Send + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnBlocking< std::thread::Result<Out> >, Out: 'static + Send
+	{
+		self.spawn_blocking_with_token( move |_| f() )
+	}
+
+
+	/// Run a blocking closure that gets a [`CancelToken`], see [`Nursery::nurse_blocking_with_token`].
+	/// Returns the [`TaskId`] assigned to it. A [timeout](TaskBuilder::timeout) doesn't apply.
+	//
+	#[ cfg_attr( feature = "tracing", track_caller ) ]
+	//
+	pub fn spawn_blocking_with_token( self, f: impl FnOnce( CancelToken ) -> Out + Send + 'static ) -> Result<TaskId, NurseErr>
+
+		where S: SpawnBlocking< std::thread::Result<Out> >, Out: 'static + Send
+	{
+		self.nursery.nurse_blocking_meta( f, &self.meta )
+	}
+}
+
+
+
+impl<S, T, E> TaskBuilder<'_, S, Result<T, E>>
+{
+	/// Drop the task if it doesn't finish within `dur`, counted from this call, in which case it
+	/// yields `Err(NurseErr::Timeout.into())`. See [`Nursery::nurse_timeout`].
+	//
+	pub fn timeout( mut self, dur: Duration ) -> Self
+
+		where S: Timer, E: From<NurseErr>
+	{
+		self.deadline = Some( Deadline::new( self.nursery.sleep( dur ), || Err( NurseErr::Timeout.into() ) ) );
+		self
+	}
+}
+
+
+
+/// What we know about a task besides it's future.
+//
+#[ derive( Clone, Debug ) ]
+//
+pub(crate) struct TaskMeta
+{
+	pub(crate) name     : Name             ,
+	pub(crate) critical : bool             ,
+	pub(crate) timed_out: Option<TimedOut> ,
+}
+
+
+
+impl Default for TaskMeta
+{
+	fn default() -> Self
+	{
+		Self{ name: None, critical: true, timed_out: None }
+	}
+}
@@ -0,0 +1,295 @@
+use crate:: { import::*, TaskId };
+
+
+/// A handle to a single task, returned by [`Nursery::nurse_with_handle`](crate::Nursery::nurse_with_handle).
+///
+/// The output of the task is delivered to this handle instead of the [`NurseryStream`](crate::NurseryStream).
+/// Awaiting the handle resolves to `Some(output)`, or to `None` if the task was aborted or dropped
+/// together with the `NurseryStream` before it finished. If the task panics, the panic is reported
+/// by whichever side polls it first, and the other side sees the task as aborted.
+///
+/// The nursery still manages the task, so `NurseryStream` won't finish before the task is done,
+/// and dropping `NurseryStream` drops the task. Dropping the handle does not cancel the task,
+/// use [`TaskHandle::abort`] for that.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+#[ must_use = "TaskHandle does nothing unless polled. If you don't need it, use nurse instead." ]
+//
+pub struct TaskHandle<Out>
+{
+	id  : TaskId               ,
+	slot: Arc< TaskSlot<Out> > ,
+}
+
+
+
+impl<Out> TaskHandle<Out>
+{
+	pub(crate) fn new( id: TaskId, slot: Arc< TaskSlot<Out> > ) -> Self
+	{
+		Self{ id, slot }
+	}
+
+
+	/// The id of this task.
+	//
+	pub fn id( &self ) -> TaskId
+	{
+		self.id
+	}
+
+
+	/// Cancel this task by dropping it. It is removed from the `NurseryStream` and awaiting
+	/// this handle will resolve to `None`. Has no effect if the task already finished.
+	//
+	pub fn abort( &self )
+	{
+		self.slot.abort();
+	}
+
+
+	/// Whether the task has finished or was aborted. A task is considered finished when
+	/// it's output has been observed, either by this handle or by the `NurseryStream`.
+	//
+	pub fn is_finished( &self ) -> bool
+	{
+		!matches!( *self.slot.lock(), State::Running(_) )
+	}
+}
+
+
+
+impl<Out> Future for TaskHandle<Out>
+
+	where Out: 'static
+{
+	type Output = Option<Out>;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		let mut state = ready!( self.slot.poll_state( Side::Handle, cx ) );
+
+		match std::mem::replace( &mut *state, State::Taken )
+		{
+			State::Finished(out) =>
+			{
+				// Let the NurseryStream know it can drop this task.
+				//
+				self.slot.wakers.wake_side( Side::Stream );
+
+				Poll::Ready( Some(out) )
+			}
+
+			State::Aborted => { *state = State::Aborted; Poll::Ready( None ) }
+
+			// Polled after completion.
+			//
+			_ => Poll::Pending,
+		}
+	}
+}
+
+
+
+impl<Out> FusedFuture for TaskHandle<Out>
+
+	where Out: 'static
+{
+	fn is_terminated( &self ) -> bool
+	{
+		matches!( *self.slot.lock(), State::Taken )
+	}
+}
+
+
+
+/// The state of a task shared between a [`TaskHandle`] and the `NurseryStream`. Both sides
+/// poll the same `JoinHandle`. Whichever sees the output first stores it for the handle.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct TaskSlot<Out>
+{
+	state : Mutex< State<Out> > ,
+	wakers: Arc< SlotWakers >   ,
+}
+
+
+#[ derive( Debug ) ]
+//
+pub(crate) enum State<Out>
+{
+	Running ( JoinHandle<Out> ),
+	Finished( Out             ),
+	Taken                      ,
+	Aborted                    ,
+}
+
+
+#[ derive( Debug, Clone, Copy ) ]
+//
+pub(crate) enum Side
+{
+	Handle,
+	Stream,
+}
+
+
+
+impl<Out> TaskSlot<Out>
+{
+	pub(crate) fn new( handle: JoinHandle<Out> ) -> Arc<Self>
+	{
+		Arc::new( Self
+		{
+			state : Mutex::new( State::Running(handle) ) ,
+			wakers: Arc::new( SlotWakers::default() )    ,
+		})
+	}
+
+
+	fn lock( &self ) -> MutexGuard<'_, State<Out>>
+	{
+		// We never panic while holding the lock.
+		//
+		self.state.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+
+
+	/// Poll the task on behalf of one side. Returns the lock on the state once the task
+	/// is no longer running.
+	//
+	pub(crate) fn poll_state( &self, side: Side, cx: &mut Context<'_> ) -> Poll< MutexGuard<'_, State<Out>> >
+
+		where Out: 'static
+	{
+		self.wakers.set( side, cx.waker() );
+
+		let mut state = self.lock();
+
+		if let State::Running( handle ) = &mut *state
+		{
+			// Poll with a waker that wakes both sides, so whoever is still interested
+			// will be woken up when the task finishes.
+			//
+			let waker  = Waker::from( self.wakers.clone() );
+			let mut cx = Context::from_waker( &waker );
+
+			let out = match std::panic::catch_unwind( AssertUnwindSafe( || Pin::new( handle ).poll( &mut cx ) ) )
+			{
+				Ok ( out     ) => ready!( out ),
+
+				// The JoinHandle propagated a panic from the task. The other side sees the task
+				// as aborted, as the JoinHandle can not be polled again.
+				//
+				Err( payload ) =>
+				{
+					*state = State::Aborted;
+					drop( state );
+
+					self.wakers.wake_side( side.other() );
+					std::panic::resume_unwind( payload );
+				}
+			};
+
+			*state = State::Finished( out );
+
+			self.wakers.wake_side( side.other() );
+		}
+
+		Poll::Ready( state )
+	}
+
+
+	/// Drop the task if it is still running.
+	//
+	pub(crate) fn abort( &self )
+	{
+		let mut state = self.lock();
+
+		if let State::Running(_) = &*state
+		{
+			*state = State::Aborted;
+			drop( state );
+
+			self.wakers.wake_side( Side::Handle );
+			self.wakers.wake_side( Side::Stream );
+		}
+	}
+}
+
+
+
+impl Side
+{
+	fn other( self ) -> Self
+	{
+		match self
+		{
+			Side::Handle => Side::Stream,
+			Side::Stream => Side::Handle,
+		}
+	}
+}
+
+
+
+/// The wakers of both sides of a [`TaskSlot`].
+//
+#[ derive( Debug, Default ) ]
+//
+pub(crate) struct SlotWakers
+{
+	handle: Mutex< Option<Waker> >,
+	stream: Mutex< Option<Waker> >,
+}
+
+
+
+impl SlotWakers
+{
+	fn get( &self, side: Side ) -> MutexGuard<'_, Option<Waker>>
+	{
+		let waker = match side
+		{
+			Side::Handle => &self.handle,
+			Side::Stream => &self.stream,
+		};
+
+		waker.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+
+
+	fn set( &self, side: Side, waker: &Waker )
+	{
+		*self.get( side ) = Some( waker.clone() );
+	}
+
+
+	fn wake_side( &self, side: Side )
+	{
+		let waker = self.get( side ).take();
+
+		if let Some(waker) = waker { waker.wake() }
+	}
+}
+
+
+
+impl Wake for SlotWakers
+{
+	fn wake( self: Arc<Self> )
+	{
+		self.wake_by_ref();
+	}
+
+
+	fn wake_by_ref( self: &Arc<Self> )
+	{
+		self.wake_side( Side::Handle );
+		self.wake_side( Side::Stream );
+	}
+}
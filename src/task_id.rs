@@ -0,0 +1,58 @@
+use crate:: { import::* };
+
+
+/// Identifies a task spawned on a [`Nursery`](crate::Nursery). Ids are assigned in the order
+/// tasks are spawned, starting from 0, and are unique within a nursery and all of it's clones.
+/// A task that fails to spawn still uses up an id.
+///
+/// Use [`NurseryStream::with_ids`](crate::NurseryStream::with_ids) to get the id together
+/// with the output of each task.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug ) ]
+//
+pub struct TaskId( u64 );
+
+
+
+impl TaskId
+{
+	/// The id as a number.
+	//
+	pub fn as_u64( self ) -> u64
+	{
+		self.0
+	}
+}
+
+
+
+impl std::fmt::Display for TaskId
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		write!( f, "{}", self.0 )
+	}
+}
+
+
+
+/// Hands out increasing task ids. Shared by all clones of a nursery.
+//
+#[ derive( Debug, Default ) ]
+//
+pub(crate) struct IdGen
+{
+	next: AtomicU64,
+}
+
+
+
+impl IdGen
+{
+	pub(crate) fn next( &self ) -> TaskId
+	{
+		TaskId( self.next.fetch_add( 1, Ordering::Relaxed ) )
+	}
+}
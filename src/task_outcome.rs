@@ -0,0 +1,130 @@
+use crate:: { import::*, NurseryStream, TaskId };
+use std  :: { any::Any };
+
+
+/// How a task ended, as yielded by [`Outcomes`].
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub enum TaskOutcome<Out>
+{
+	/// The task returned this output.
+	//
+	Completed( Out ),
+
+	/// The task panicked. Without a [`PanicPolicy`](crate::PanicPolicy), the payload is whatever the
+	/// executor resumes when polling the `JoinHandle`, which might be a message of the executor rather
+	/// than the original payload.
+	//
+	Panicked( Box<dyn Any + Send> ),
+
+	/// The task was dropped by the `NurseryStream` before it finished, eg. because a sibling
+	/// panicked with [`PanicPolicy::CancelSiblings`](crate::PanicPolicy::CancelSiblings) or because
+	/// only [background tasks](crate::TaskBuilder::critical) were left.
+	//
+	Cancelled,
+
+	/// The task was dropped because it's timeout or the deadline of the nursery expired.
+	//
+	TimedOut,
+}
+
+
+
+impl<Out> TaskOutcome<Out>
+{
+	/// Whether the task returned an output.
+	//
+	pub fn is_completed( &self ) -> bool
+	{
+		matches!( self, TaskOutcome::Completed(_) )
+	}
+
+
+	/// The output of the task, if it completed.
+	//
+	pub fn completed( self ) -> Option<Out>
+	{
+		match self
+		{
+			TaskOutcome::Completed( out ) => Some( out ),
+			_                             => None       ,
+		}
+	}
+}
+
+
+
+/// Stream adapter returned by [`NurseryStream::outcomes`]. Yields the id, the name and the [`TaskOutcome`]
+/// of every task of the nursery, including those of [child nurseries](crate::Nursery::child). The name is
+/// `None` unless the task was given one with [`TaskBuilder::name`](crate::TaskBuilder::name).
+///
+/// Panics never resume on the thread polling this stream, whatever the [`PanicPolicy`](crate::PanicPolicy).
+/// With [`PanicPolicy::CancelSiblings`](crate::PanicPolicy::CancelSiblings), the panicked task is
+/// followed by the other tasks as [`TaskOutcome::Cancelled`], after which the stream ends.
+///
+/// Tasks spawned with a [`TaskHandle`](crate::TaskHandle) report to their handle and don't appear here.
+/// Tasks that are still running when this stream is dropped are dropped without being reported.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+#[ must_use = "streams do nothing unless polled" ]
+//
+pub struct Outcomes<Out>
+{
+	inner: NurseryStream<Out>,
+}
+
+
+
+impl<Out> Outcomes<Out>
+{
+	pub(crate) fn new( inner: NurseryStream<Out> ) -> Self
+	{
+		Self{ inner }
+	}
+
+
+	/// Get back the wrapped `NurseryStream`.
+	//
+	pub fn into_inner( self ) -> NurseryStream<Out>
+	{
+		self.inner
+	}
+}
+
+
+
+impl<Out> Stream for Outcomes<Out>
+
+	where Out: 'static
+{
+	type Item = (TaskId, Option< Arc<str> >, TaskOutcome<Out>);
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		self.inner.poll_outcome( cx )
+	}
+
+
+	fn size_hint( &self ) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+
+
+impl<Out> FusedStream for Outcomes<Out>
+
+	where Out: 'static
+{
+	fn is_terminated( &self ) -> bool
+	{
+		FusedStream::is_terminated( &self.inner )
+	}
+}
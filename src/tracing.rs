@@ -9,6 +9,8 @@ use
 
 impl<T, Out> Nurse<Out> for Instrumented<T> where T: Nurse<Out>, Out: 'static + Send
 {
+	#[ track_caller ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		let fut = future.instrument( self.span().clone() );
@@ -21,6 +23,8 @@ impl<T, Out> Nurse<Out> for Instrumented<T> where T: Nurse<Out>, Out: 'static +
 
 impl<T, Out> Nurse<Out> for WithDispatch<T> where T: Nurse<Out>, Out: 'static + Send
 {
+	#[ track_caller ]
+	//
 	fn nurse_obj( &self, future: FutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		let fut = self.with_dispatch( future );
@@ -33,6 +37,8 @@ impl<T, Out> Nurse<Out> for WithDispatch<T> where T: Nurse<Out>, Out: 'static +
 
 impl<T, Out> LocalNurse<Out> for Instrumented<T> where T: LocalNurse<Out>, Out: 'static
 {
+	#[ track_caller ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		let fut = future.instrument( self.span().clone() );
@@ -46,6 +52,8 @@ impl<T, Out> LocalNurse<Out> for Instrumented<T> where T: LocalNurse<Out>, Out:
 
 impl<T, Out> LocalNurse<Out> for WithDispatch<T> where T: LocalNurse<Out>, Out: 'static
 {
+	#[ track_caller ]
+	//
 	fn nurse_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<(), NurseErr>
 	{
 		let fut = self.with_dispatch(future);
@@ -0,0 +1,154 @@
+use crate:: { import::*, NurseryStream, CancelToken };
+
+/// A [`NurseryStream`] for tasks that return a `Result`, with a fail-fast error policy.
+///
+/// Awaiting it resolves to `Ok(Vec<T>)` with the outputs of all tasks once they are all done,
+/// or to the first `Err` that any task returns. When an error comes in, the [`CancelToken`]
+/// is tripped and all sibling tasks are dropped, so you don't need to call `try_next` in
+/// a loop yourself.
+///
+/// Just like `NurseryStream`, the `Nursery` can keep spawning while this is being awaited.
+/// You can also poll it as a `Stream`, in which case the tasks are dropped as soon as the stream
+/// yields an `Err`.
+///
+/// Create one with [`Nursery::new_try`](crate::Nursery::new_try) or by converting a `NurseryStream`
+/// with `From`.
+//
+#[ derive( Debug ) ]
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "implementation" )) ) ]
+//
+pub struct TryNurseryStream<T, E>
+{
+	inner  : NurseryStream< Result<T, E> > ,
+	outputs: Vec<T>                        ,
+}
+
+
+
+impl<T, E> TryNurseryStream<T, E>
+{
+	/// Close the nursery. See [`NurseryStream::close_nursery`].
+	//
+	pub fn close_nursery( &mut self ) -> &mut Self
+	{
+		self.inner.close_nursery();
+		self
+	}
+
+
+	/// Request cooperative cancellation of all tasks. See [`NurseryStream::cancel`].
+	//
+	pub fn cancel( &mut self ) -> &mut Self
+	{
+		self.inner.cancel();
+		self
+	}
+
+
+	/// Get the [`CancelToken`] of this nursery.
+	//
+	pub fn cancel_token( &self ) -> CancelToken
+	{
+		self.inner.cancel_token()
+	}
+
+
+	/// Get back the wrapped `NurseryStream`. Outputs that were already collected by
+	/// polling the `Future` impl are lost.
+	//
+	pub fn into_inner( self ) -> NurseryStream< Result<T, E> >
+	{
+		self.inner
+	}
+}
+
+
+
+// We never pin project to our fields.
+//
+impl<T, E> Unpin for TryNurseryStream<T, E> {}
+
+
+
+impl<T, E> From< NurseryStream<Result<T, E>> > for TryNurseryStream<T, E>
+{
+	fn from( inner: NurseryStream<Result<T, E>> ) -> Self
+	{
+		Self { inner, outputs: Vec::new() }
+	}
+}
+
+
+
+impl<T, E> Stream for TryNurseryStream<T, E>
+
+	where T: 'static, E: 'static
+{
+	type Item = Result<T, E>;
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		let out = ready!( self.inner.poll_next_unpin( cx ) );
+
+		if let Some( Err(_) ) = &out
+		{
+			self.inner.cancel_token().cancel();
+			self.inner.drop_tasks( cx );
+		}
+
+		Poll::Ready( out )
+	}
+
+
+	fn size_hint( &self ) -> (usize, Option<usize>)
+	{
+		self.inner.size_hint()
+	}
+}
+
+
+
+impl<T, E> Future for TryNurseryStream<T, E>
+
+	where T: 'static, E: 'static
+{
+	type Output = Result<Vec<T>, E>;
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		loop
+		{
+			match ready!( self.as_mut().poll_next(cx) )
+			{
+				Some( Ok (out) ) => self.outputs.push( out ),
+				Some( Err(err) ) => return Poll::Ready( Err(err) ),
+				None             => return Poll::Ready( Ok( std::mem::take( &mut self.outputs ) ) ),
+			}
+		}
+	}
+}
+
+
+
+impl<T, E> FusedFuture for TryNurseryStream<T, E>
+
+	where T: 'static, E: 'static
+{
+	fn is_terminated( &self ) -> bool
+	{
+		FusedStream::is_terminated( &self.inner )
+	}
+}
+
+
+
+impl<T, E> FusedStream for TryNurseryStream<T, E>
+
+	where T: 'static, E: 'static
+{
+	fn is_terminated( &self ) -> bool
+	{
+		FusedStream::is_terminated( &self.inner )
+	}
+}
@@ -0,0 +1,59 @@
+use crate:: { import::* };
+
+
+/// A set of wakers for tasks waiting on some shared state. Each waiter keeps a key so it can
+/// replace it's waker when polled again and remove it when it stops waiting.
+//
+#[ derive( Debug, Default ) ]
+//
+pub(crate) struct WakerSet
+{
+	next_key: AtomicUsize                    ,
+	wakers  : Mutex< HashMap<usize, Waker> > ,
+}
+
+
+
+impl WakerSet
+{
+	/// Store the waker for this waiter. Assigns a key if it doesn't have one yet.
+	///
+	/// Callers must check their condition again after registering, otherwise they can
+	/// miss a call to `wake_all` that happened in between.
+	//
+	pub(crate) fn register( &self, key: &mut Option<usize>, waker: &Waker )
+	{
+		let key = *key.get_or_insert_with( || self.next_key.fetch_add( 1, Ordering::Relaxed ) );
+
+		self.lock().insert( key, waker.clone() );
+	}
+
+
+	/// Remove the waker of this waiter if it has one.
+	//
+	pub(crate) fn remove( &self, key: &mut Option<usize> )
+	{
+		if let Some(key) = key.take()
+		{
+			self.lock().remove( &key );
+		}
+	}
+
+
+	/// Wake up and remove all waiters.
+	//
+	pub(crate) fn wake_all( &self )
+	{
+		let wakers: Vec<Waker> = self.lock().drain().map( |(_, w)| w ).collect();
+
+		for waker in wakers { waker.wake(); }
+	}
+
+
+	fn lock( &self ) -> MutexGuard<'_, HashMap<usize, Waker>>
+	{
+		// We never panic while holding the lock, but if we did the map is still valid.
+		//
+		self.wakers.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+}
@@ -0,0 +1,166 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ The stream ends when the critical tasks are done, without dropping the nursery, and drops the background tasks.
+// ✔ Outputs of background tasks that finish before the critical tasks are yielded.
+// ✔ The nursery is closed and cancelled after the background tasks are dropped.
+// ✔ In ordered mode, background tasks don't hold up the order.
+// ✔ Without background tasks, the stream still waits for the nursery to be dropped.
+// ✔ A nursery that only spawned background tasks ends once the nursery is dropped.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+use futures::future::{ select, Either };
+
+
+
+// The stream ends when the critical tasks are done, without dropping the nursery, and drops the background tasks.
+//
+#[ async_std::test ]
+//
+async fn background_dropped() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let beats             = Arc::new( AtomicUsize::new( 0 ) );
+	let beats2            = beats.clone();
+
+	nursery.nurse( async { Delay::new( Duration::from_millis(20) ).await; 1 } )?;
+
+	nursery.task().critical( false ).spawn( async move
+	{
+		loop
+		{
+			beats2.fetch_add( 1, SeqCst );
+			Delay::new( Duration::from_millis(1) ).await;
+		}
+
+	})?;
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![1], outputs );
+
+	// The heartbeat no longer runs. Give a poll that was in progress on another thread the time to finish.
+	//
+	Delay::new( Duration::from_millis(5) ).await;
+
+	let beats_at_end = beats.load( SeqCst );
+	Delay::new( Duration::from_millis(20) ).await;
+
+	assert_eq!( beats_at_end, beats.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// Outputs of background tasks that finish before the critical tasks are yielded.
+//
+#[ async_std::test ]
+//
+async fn background_output() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { Delay::new( Duration::from_millis(20) ).await; 1 } )?;
+	nursery.task().critical( false ).spawn( async { 2 } )?;
+	nursery.task().critical( false ).spawn( futures::future::pending() )?;
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![ 2, 1 ], outputs );
+
+	Ok(())
+}
+
+
+
+// The nursery is closed and cancelled after the background tasks are dropped.
+//
+#[ async_std::test ]
+//
+async fn background_closed() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { 1 } )?;
+	nursery.task().critical( false ).spawn( futures::future::pending() )?;
+
+	output.await;
+
+	assert!   ( nursery.cancel_token().is_cancelled()               );
+	assert_eq!( Err( NurseErr::Closed ), nursery.nurse( async { 3 } ) );
+	assert_eq!( 0, nursery.active_tasks()                           );
+
+	Ok(())
+}
+
+
+
+// In ordered mode, background tasks don't hold up the order.
+//
+#[ async_std::test ]
+//
+async fn background_ordered() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new_ordered( AsyncStd );
+
+	nursery.task().critical( false ).spawn( futures::future::pending() )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(10) ).await; 1 } )?;
+	nursery.nurse( async { 2 } )?;
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![ 1, 2 ], outputs );
+
+	Ok(())
+}
+
+
+
+// Without background tasks, the stream still waits for the nursery to be dropped.
+//
+#[ async_std::test ]
+//
+async fn background_none() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { 1 } )?;
+
+	assert_eq!( Some(1), output.next().await );
+
+	let timeout = Delay::new( Duration::from_millis(20) );
+
+	assert!( matches!( select( output.next(), timeout ).await, Either::Right(_) ) );
+
+	drop( nursery );
+
+	assert_eq!( None, output.next().await );
+
+	Ok(())
+}
+
+
+
+// A nursery that only spawned background tasks ends once the nursery is dropped.
+//
+#[ async_std::test ]
+//
+async fn background_only() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery.task().critical( false ).spawn( futures::future::pending() )?;
+
+	drop( nursery );
+
+	let timeout = Delay::new( Duration::from_secs(5) );
+
+	assert!( matches!( select( output.collect::<Vec<_>>(), timeout ).await, Either::Left( (outputs, _) ) if outputs.is_empty() ) );
+
+	Ok(())
+}
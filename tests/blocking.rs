@@ -0,0 +1,207 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ The output of a blocking task is yielded and the stream waits for it.
+// ✔ The token of a blocking task is cancelled when the nursery is cancelled.
+// ✔ The token of a blocking task is cancelled when the NurseryStream is dropped.
+// ✔ Panics in blocking tasks are handled by the PanicPolicy.
+// ✔ A blocking task holds it's slot in a bounded nursery until it finishes.
+// ✔ The TaskBuilder gives blocking tasks a name and runs them in the background.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// The output of a blocking task is yielded and the stream waits for it.
+//
+#[ async_std::test ]
+//
+async fn blocking_output() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let a = nursery.nurse_blocking( || { std::thread::sleep( Duration::from_millis(20) ); 1 } )?;
+	let b = nursery.nurse_with_id( async { 2 } )?;
+
+	drop( nursery );
+
+	let mut outputs: Vec<(TaskId, usize)> = output.with_ids().collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![ (a, 1), (b, 2) ], outputs );
+
+	Ok(())
+}
+
+
+
+// The token of a blocking task is cancelled when the nursery is cancelled.
+//
+#[ async_std::test ]
+//
+async fn blocking_cancel() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.nurse_blocking_with_token( |token|
+	{
+		let mut rounds = 0;
+
+		while !token.is_cancelled()
+		{
+			std::thread::sleep( Duration::from_millis(1) );
+			rounds += 1;
+		}
+
+		rounds
+	})?;
+
+	drop( nursery );
+
+	Delay::new( Duration::from_millis(10) ).await;
+	output.cancel();
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( 1, outputs.len() );
+	assert!   ( outputs[0] > 0   );
+
+	Ok(())
+}
+
+
+
+// The token of a blocking task is cancelled when the NurseryStream is dropped.
+//
+#[ async_std::test ]
+//
+async fn blocking_drop_stream() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let (tx, rx)          = futures::channel::oneshot::channel();
+
+	nursery.nurse_blocking_with_token( move |token|
+	{
+		while !token.is_cancelled()
+		{
+			std::thread::sleep( Duration::from_millis(1) );
+		}
+
+		tx.send( () ).expect( "send" );
+	})?;
+
+	drop( output );
+
+	assert!( rx.await.is_ok() );
+
+	Ok(())
+}
+
+
+
+// Panics in blocking tasks are handled by the PanicPolicy.
+//
+#[ async_std::test ]
+//
+async fn blocking_panic() -> DynResult<()>
+{
+	let (mut nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::map( |_| 0 ) );
+
+	nursery.nurse_blocking( || -> usize { panic!( "boom" ) } )?;
+	nursery.nurse_blocking( || 1 )?;
+
+	drop( nursery );
+
+	let mut outputs: Vec<usize> = output.collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![ 0, 1 ], outputs );
+
+
+	let (mut nursery, mut output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::CancelSiblings );
+
+	nursery.nurse( futures::future::pending() )?;
+	nursery.nurse_blocking( || panic!( "boom" ) )?;
+
+	drop( nursery );
+
+	assert_eq!( None, output.next().await );
+
+	let payload = output.take_panic().expect( "panic payload" );
+
+	assert_eq!( Some( &"boom" ), payload.downcast_ref::<&str>() );
+
+	Ok(())
+}
+
+
+
+// A blocking task holds it's slot in a bounded nursery until it finishes.
+//
+#[ async_std::test ]
+//
+async fn blocking_bounded() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::bounded( AsyncStd, 1 );
+
+	nursery.nurse_blocking( || { std::thread::sleep( Duration::from_millis(10) ); 1 } )?;
+
+	assert_eq!( Err( NurseErr::Full ), nursery.nurse_blocking( || 2 ).map( |_| () ) );
+	assert_eq!( Some(1), output.next().await );
+
+	nursery.nurse_blocking( || 3 )?;
+
+	drop( nursery );
+
+	assert_eq!( Some(3), output.next().await );
+
+	Ok(())
+}
+
+
+
+// The TaskBuilder gives blocking tasks a name and runs them in the background.
+//
+#[ async_std::test ]
+//
+async fn blocking_builder() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let stopped           = Arc::new( AtomicBool::new( false ) );
+	let flag              = stopped.clone();
+
+	let a = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(10) ).await; 1 } )?;
+
+	let b = nursery.task().name( "heartbeat" ).critical( false ).spawn_blocking_with_token( move |token|
+	{
+		while !token.is_cancelled()
+		{
+			std::thread::sleep( Duration::from_millis(1) );
+		}
+
+		flag.store( true, SeqCst );
+		2
+	})?;
+
+	let mut outcomes: Vec<_> = output.outcomes().map( |(id, name, outcome)| (id, name, outcome.is_completed()) ).collect().await;
+	outcomes.sort_unstable_by_key( |(id, ..)| *id );
+
+	assert_eq!( vec![ (a, None, true), (b, Some( Arc::from( "heartbeat" ) ), false) ], outcomes );
+
+	// The token tells the closure to stop.
+	//
+	while !stopped.load( SeqCst )
+	{
+		Delay::new( Duration::from_millis(1) ).await;
+	}
+
+	Ok(())
+}
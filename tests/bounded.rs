@@ -0,0 +1,117 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ nurse returns NurseErr::Full when the limit is reached.
+// ✔ nurse_async waits for a slot without the stream being polled.
+// ✔ Sink::poll_ready waits for a slot.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+use futures::task::FutureObj;
+
+
+
+// nurse returns NurseErr::Full when the limit is reached.
+//
+#[ async_std::test ]
+//
+async fn bounded_full() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::bounded( AsyncStd, 2 );
+	let token             = nursery.cancel_token();
+
+	for _ in 0..2
+	{
+		let token = token.clone();
+		nursery.nurse( async move { token.cancelled().await; 1 } )?;
+	}
+
+	assert_eq!( NurseErr::Full, nursery.nurse( async { 1 } ).unwrap_err() );
+
+	drop(nursery);
+	token.cancel();
+
+	let sum = output.fold( 0, |acc, x| async move { acc + x } ).await;
+
+	assert_eq!( 2, sum );
+
+	Ok(())
+}
+
+
+
+// nurse_async waits for a slot without the stream being polled.
+//
+#[ async_std::test ]
+//
+async fn bounded_nurse_async() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::bounded( AsyncStd, 2 );
+	let running           = Arc::new( AtomicUsize::new(0) );
+	let max               = Arc::new( AtomicUsize::new(0) );
+
+	for _ in 0..10
+	{
+		let running = running.clone();
+		let max     = max.clone();
+
+		nursery.nurse_async( async move
+		{
+			let now = running.fetch_add( 1, SeqCst ) + 1;
+			max.fetch_max( now, SeqCst );
+
+			Delay::new( Duration::from_millis(5) ).await;
+
+			running.fetch_sub( 1, SeqCst );
+			1
+
+		}).await?;
+	}
+
+	drop(nursery);
+
+	let sum = output.fold( 0, |acc, x| async move { acc + x } ).await;
+
+	assert_eq!( 10, sum                );
+	assert_eq!( 2 , max.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// Sink::poll_ready waits for a slot.
+//
+#[ async_std::test ]
+//
+async fn bounded_sink() -> DynResult<()>
+{
+	let (mut nursery, output) = Nursery::bounded( AsyncStd, 1 );
+	let running               = Arc::new( AtomicUsize::new(0) );
+
+	for _ in 0..5
+	{
+		let running = running.clone();
+
+		let fut = async move
+		{
+			assert_eq!( 0, running.fetch_add( 1, SeqCst ) );
+			Delay::new( Duration::from_millis(5) ).await;
+			running.fetch_sub( 1, SeqCst );
+			1
+		};
+
+		nursery.send( FutureObj::new( Box::new(fut) ) ).await?;
+	}
+
+	drop(nursery);
+
+	let sum = output.fold( 0, |acc, x| async move { acc + x } ).await;
+
+	assert_eq!( 5, sum );
+
+	Ok(())
+}
@@ -0,0 +1,97 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ NurseryStream::cancel trips the token and let's tasks do cleanup.
+// ✔ CancelToken::cancelled wakes up waiting tasks.
+// ✔ NurseryStream::cancel closes the nursery.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// NurseryStream::cancel trips the token and let's tasks do cleanup.
+//
+#[ async_std::test ]
+//
+async fn cancel_cleanup() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	for _ in 0..5
+	{
+		let token = nursery.cancel_token();
+
+		nursery.nurse( async move
+		{
+			while !token.is_cancelled()
+			{
+				Delay::new( Duration::from_millis(5) ).await;
+			}
+
+			// cleanup
+			//
+			Delay::new( Duration::from_millis(5) ).await;
+			1
+		})?;
+	}
+
+	output.cancel();
+
+	assert!( output.cancel_token().is_cancelled() );
+
+	// We didn't drop the nursery, but cancel closes it.
+	//
+	let sum = output.fold( 0, |acc, x| async move { acc + x } ).await;
+
+	assert_eq!( 5, sum );
+
+	Ok(())
+}
+
+
+
+// CancelToken::cancelled wakes up waiting tasks.
+//
+#[ async_std::test ]
+//
+async fn cancelled_wakes() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+	let token                 = nursery.cancel_token();
+
+	nursery.nurse( async move { token.cancelled().await; 5 } )?;
+	nursery.nurse( async move { Delay::new( Duration::from_millis(10) ).await; 5 } )?;
+
+	assert_eq!( Some(5), output.next().await );
+
+	output.cancel();
+
+	assert_eq!( Some(5), output.next().await );
+	assert_eq!( None   , output.next().await );
+
+	Ok(())
+}
+
+
+
+// NurseryStream::cancel closes the nursery.
+//
+#[ async_std::test ]
+//
+async fn cancel_closes() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	output.cancel();
+
+	assert_eq!( NurseErr::Closed, nursery.nurse( async {} ).unwrap_err() );
+	assert!( nursery.cancel_token().is_cancelled() );
+
+	output.await;
+
+	Ok(())
+}
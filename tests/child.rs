@@ -0,0 +1,126 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Outputs of a child nursery are yielded by the parent, which waits for the child to finish.
+// ✔ Cancelling the parent cancels and closes the child.
+// ✔ Cancelling the child does not affect the parent.
+// ✔ Dropping the parent NurseryStream drops the tasks of the child.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Outputs of a child nursery are yielded by the parent, which waits for the child to finish.
+//
+#[ async_std::test ]
+//
+async fn child_outputs() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let child             = nursery.child()?;
+	let grand_child       = child.child()?;
+
+	nursery.nurse( async { 1 } )?;
+	child  .nurse( async { Delay::new( Duration::from_millis(10) ).await; 2 } )?;
+
+	grand_child.nurse( async { Delay::new( Duration::from_millis(20) ).await; 3 } )?;
+
+	drop( nursery     );
+	drop( child       );
+	drop( grand_child );
+
+	let mut outputs: Vec<usize> = output.collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![1, 2, 3], outputs );
+
+	Ok(())
+}
+
+
+
+// Cancelling the parent cancels and closes the child.
+//
+#[ async_std::test ]
+//
+async fn child_cancel_parent() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+	let child                 = nursery.child()?;
+	let token                 = child.cancel_token();
+
+	child.nurse( async move { token.cancelled().await; 2 } )?;
+
+	output.cancel();
+
+	// The child nursery is still alive, but the parent ends.
+	//
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![2], outputs );
+	assert!( child.cancel_token().is_cancelled() );
+	assert_eq!( Err( NurseErr::Closed ), child.nurse( async { 3 } ) );
+
+	Ok(())
+}
+
+
+
+// Cancelling the child does not affect the parent.
+//
+#[ async_std::test ]
+//
+async fn child_cancel_child() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let child             = nursery.child()?;
+
+	child.cancel_token().cancel();
+
+	assert!( !nursery.cancel_token().is_cancelled() );
+
+	nursery.nurse( async { 1 } )?;
+
+	drop( nursery );
+	drop( child   );
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![1], outputs );
+
+	Ok(())
+}
+
+
+
+// Dropping the parent NurseryStream drops the tasks of the child.
+//
+#[ async_std::test ]
+//
+async fn child_drop_parent() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+	let child                 = nursery.child()?;
+	let ran                   = Arc::new( AtomicBool::new( false ) );
+	let ran2                  = ran.clone();
+
+	child.nurse( async move { Delay::new( Duration::from_millis(20) ).await; ran2.store( true, SeqCst ); } )?;
+	nursery.nurse( async {} )?;
+
+	// Make sure the child has reached the parent NurseryStream.
+	//
+	output.next().await;
+
+	drop( output );
+
+	Delay::new( Duration::from_millis(50) ).await;
+
+	assert!( !ran.load( SeqCst ) );
+	assert_eq!( Err( NurseErr::Closed ), child.nurse( async {} ) );
+
+	Ok(())
+}
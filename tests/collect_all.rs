@@ -0,0 +1,145 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ collect_all resolves to all outputs when no task fails.
+// ✔ collect_all lets all tasks run and reports every error with the id of the task.
+// ✔ collect_all_budget drops the remaining tasks once more errors than the budget occurred.
+// ✔ collect_all_budget(0) resolves to all outputs when no task fails.
+// ✔ NurseryErrors implements Error and Display.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// collect_all resolves to all outputs when no task fails.
+//
+#[ async_std::test ]
+//
+async fn collect_all_ok() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, NurseErr>>::new( AsyncStd );
+
+	nursery.nurse( async { Ok(1) } )?;
+	nursery.nurse( async { Ok(2) } )?;
+
+	drop( nursery );
+
+	let mut outputs = output.collect_all().await?;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![ 1, 2 ], outputs );
+
+	Ok(())
+}
+
+
+
+// collect_all lets all tasks run and reports every error with the id of the task.
+//
+#[ async_std::test ]
+//
+async fn collect_all_errors() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let done              = Arc::new( AtomicUsize::new( 0 ) );
+
+	let a = nursery.nurse_with_id( async { Err( "a" ) } )?;
+
+	let done2 = done.clone();
+	nursery.nurse( async move { Delay::new( Duration::from_millis(10) ).await; done2.fetch_add( 1, SeqCst ); Ok(1) } )?;
+
+	let b = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(20) ).await; Err( "b" ) } )?;
+
+	drop( nursery );
+
+	let errors = output.collect_all().await.expect_err( "two tasks failed" );
+
+	assert_eq!( 1                            , done.load( SeqCst )                        );
+	assert_eq!( 2                            , errors.len()                               );
+	assert_eq!( vec![ (a, &"a"), (b, &"b") ] , errors.iter_with_ids().collect::<Vec<_>>() );
+	assert!   ( errors.dropped().is_empty()                                            );
+	assert_eq!( vec![ "a", "b" ]             , errors.into_iter().collect::<Vec<_>>()     );
+
+	Ok(())
+}
+
+
+
+// collect_all_budget drops the remaining tasks once more errors than the budget occurred.
+//
+#[ async_std::test ]
+//
+async fn collect_all_budget() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, &str>>::new( AsyncStd );
+
+	nursery.nurse( async { Err( "a" ) } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(5) ).await; Err( "b" ) } )?;
+
+	let slow = nursery.nurse_with_id( futures::future::pending() )?;
+
+	drop( nursery );
+
+	let errors = output.collect_all_budget( 1 ).await.expect_err( "budget exceeded" );
+
+	assert_eq!( vec![ "a", "b" ], errors.iter().copied().collect::<Vec<_>>() );
+	assert_eq!( &[ slow ]       , errors.dropped()                            );
+
+	Ok(())
+}
+
+
+
+// collect_all_budget(0) resolves to all outputs when no task fails.
+//
+#[ async_std::test ]
+//
+async fn collect_all_budget_zero() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, NurseErr>>::new( AsyncStd );
+
+	nursery.nurse( async { Ok(1) } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(5) ).await; Ok(2) } )?;
+
+	drop( nursery );
+
+	let mut outputs = output.collect_all_budget( 0 ).await?;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![ 1, 2 ], outputs );
+
+	Ok(())
+}
+
+
+
+// NurseryErrors implements Error and Display.
+//
+#[ async_std::test ]
+//
+async fn collect_all_error_trait() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, NurseErr>>::new( AsyncStd );
+
+	nursery.nurse( async { Err( NurseErr::Timeout ) } )?;
+
+	drop( nursery );
+
+	let errors = output.collect_all().await.expect_err( "task failed" );
+
+	assert_eq!( "1 task(s) failed\n  task 0: The task did not finish before the deadline.", errors.to_string() );
+
+	let source = std::error::Error::source( &errors ).expect( "source" );
+
+	assert_eq!( NurseErr::Timeout.to_string(), source.to_string() );
+
+	let boxed: Box<dyn std::error::Error> = Box::new( errors );
+
+	assert!( boxed.is::< NurseryErrors<NurseErr> >() );
+
+	Ok(())
+}
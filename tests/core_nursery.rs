@@ -0,0 +1,173 @@
+#![ cfg( feature = "core_nursery" ) ]
+
+// Tested:
+//
+// ✔ Outputs of tasks are yielded and the stream ends once the nursery is dropped.
+// ✔ LocalNurse on a LocalSpawn executor.
+// ✔ The stream ends when closed from the stream, after the tasks are done.
+// ✔ Spawning on a closed nursery or after the stream is dropped returns NurseErr::Closed.
+// ✔ Dropping the stream drops the tasks.
+// ✔ A task that the executor drops doesn't yield an output and doesn't end the stream.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+use futures::{ executor::LocalPool, future::pending };
+
+
+
+// Outputs of tasks are yielded and the stream ends once the nursery is dropped.
+//
+#[ async_std::test ]
+//
+async fn core_outputs() -> DynResult<()>
+{
+	let (nursery, output) = CoreNursery::new( AsyncStd );
+
+	nursery.nurse( async { 5 + 5 } )?;
+	nursery.nurse( async { 5 + 6 } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(10) ).await; 5 + 7 } )?;
+
+	drop( nursery );
+
+	let mut outputs: Vec<usize> = output.collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![ 10, 11, 12 ], outputs );
+
+	Ok(())
+}
+
+
+
+// LocalNurse on a LocalSpawn executor.
+//
+#[ test ]
+//
+fn core_local() -> DynResult<()>
+{
+	let mut pool          = LocalPool::new();
+	let (nursery, output) = CoreNursery::new( pool.spawner() );
+	let value             = Rc::new( 5 );
+	let value2            = value.clone();
+
+	nursery.nurse_local( async move { *value2 + 1 } )?;
+	drop( nursery );
+
+	let outputs: Vec<usize> = pool.run_until( output.collect() );
+
+	assert_eq!( vec![6], outputs );
+	assert_eq!( 1, Rc::strong_count( &value ) );
+
+	Ok(())
+}
+
+
+
+// The stream ends when closed from the stream, after the tasks are done.
+//
+#[ async_std::test ]
+//
+async fn core_close_stream() -> DynResult<()>
+{
+	let (nursery, output) = CoreNursery::new( AsyncStd );
+
+	nursery.nurse( async { Delay::new( Duration::from_millis(10) ).await; 1 } )?;
+	output.close_nursery();
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![1], outputs );
+	assert_eq!( Err( NurseErr::Closed ), nursery.nurse( async { 2 } ) );
+
+	Ok(())
+}
+
+
+
+// Spawning on a closed nursery or after the stream is dropped returns NurseErr::Closed.
+//
+#[ async_std::test ]
+//
+async fn core_closed() -> DynResult<()>
+{
+	let (nursery, output) = CoreNursery::<_, usize>::new( AsyncStd );
+
+	nursery.close_nursery();
+
+	assert_eq!( Err( NurseErr::Closed ), nursery.nurse( async { 1 } ) );
+
+
+	let (nursery, output2) = CoreNursery::<_, usize>::new( AsyncStd );
+
+	drop( output2 );
+
+	assert_eq!( Err( NurseErr::Closed ), nursery.nurse( async { 1 } ) );
+
+	output.await;
+
+	Ok(())
+}
+
+
+
+// Dropping the stream drops the tasks.
+//
+#[ async_std::test ]
+//
+async fn core_drop_stream() -> DynResult<()>
+{
+	struct Guard( Arc<AtomicBool> );
+
+	impl Drop for Guard
+	{
+		fn drop( &mut self ) { self.0.store( true, SeqCst ); }
+	}
+
+	let (nursery, output) = CoreNursery::new( AsyncStd );
+	let dropped           = Arc::new( AtomicBool::new( false ) );
+	let guard             = Guard( dropped.clone() );
+
+	nursery.nurse( async move { let _guard = guard; pending::<()>().await } )?;
+
+	// Let the task start.
+	//
+	Delay::new( Duration::from_millis(10) ).await;
+	assert!( !dropped.load( SeqCst ) );
+
+	drop( output );
+	Delay::new( Duration::from_millis(10) ).await;
+
+	assert!( dropped.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// A task that the executor drops doesn't yield an output and doesn't end the stream.
+//
+#[ test ]
+//
+fn core_executor_drops() -> DynResult<()>
+{
+	let mut pool          = LocalPool::new();
+	let (nursery, output) = CoreNursery::new( pool.spawner() );
+
+	nursery.nurse( async { 1 } )?;
+	pool.run_until_stalled();
+
+	// Never polled, the pool drops it.
+	//
+	nursery.nurse( async { 2 } )?;
+
+	drop( pool    );
+	drop( nursery );
+
+	let outputs: Vec<usize> = block_on( output.collect() );
+
+	assert_eq!( vec![1], outputs );
+
+	Ok(())
+}
@@ -0,0 +1,134 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Tasks are queued until the stream is polled, active until they finish and then counted as completed.
+// ✔ Clones of the nursery and the stream see the same counters.
+// ✔ Dropped tasks are no longer active, but are not counted as completed.
+// ✔ The tasks of a child nursery are counted by the parent as well.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Tasks are queued until the stream is polled, active until they finish and then counted as completed.
+//
+#[ async_std::test ]
+//
+async fn counters_lifecycle() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { 1 } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(20) ).await; 2 } )?;
+
+	assert_eq!( 2, nursery.spawned_total()   );
+	assert_eq!( 2, nursery.active_tasks()    );
+	assert_eq!( 2, nursery.queued_handles()  );
+	assert_eq!( 0, nursery.completed_total() );
+
+	assert_eq!( Some(1), output.next().await );
+
+	assert_eq!( 1, nursery.active_tasks()    );
+	assert_eq!( 0, nursery.queued_handles()  );
+	assert_eq!( 1, nursery.completed_total() );
+
+	drop( nursery );
+	output.await;
+
+	Ok(())
+}
+
+
+
+// Clones of the nursery and the stream see the same counters.
+//
+#[ async_std::test ]
+//
+async fn counters_shared() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+	let clone                 = nursery.clone();
+
+	nursery.nurse( async { 1 } )?;
+	clone  .nurse( async { 2 } )?;
+
+	let handle = clone.nurse_with_handle( async { 3 } )?;
+
+	assert_eq!( 3, nursery.spawned_total()  );
+	assert_eq!( 3, output .spawned_total()  );
+	assert_eq!( 3, output .active_tasks()   );
+	assert_eq!( 3, output .queued_handles() );
+
+	drop( nursery );
+	drop( clone   );
+
+	assert_eq!( Some(3), handle.await );
+
+	let outputs: Vec<usize> = output.by_ref().collect().await;
+
+	assert_eq!( 2, outputs.len()            );
+	assert_eq!( 3, output.completed_total() );
+	assert_eq!( 0, output.active_tasks()    );
+
+	Ok(())
+}
+
+
+
+// Dropped tasks are no longer active, but are not counted as completed.
+//
+#[ async_std::test ]
+//
+async fn counters_dropped() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { 1 } )?;
+	nursery.nurse( futures::future::pending() )?;
+
+	assert_eq!( Some(1), output.next().await );
+
+	drop( output );
+
+	assert_eq!( 2, nursery.spawned_total()   );
+	assert_eq!( 0, nursery.active_tasks()    );
+	assert_eq!( 1, nursery.completed_total() );
+
+	Ok(())
+}
+
+
+
+// The tasks of a child nursery are counted by the parent as well.
+//
+#[ async_std::test ]
+//
+async fn counters_child() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+	let child                 = nursery.child()?;
+
+	nursery.nurse( async { 1 } )?;
+	child  .nurse( async { 2 } )?;
+	child  .nurse( async { 3 } )?;
+
+	assert_eq!( 3, nursery.spawned_total() );
+	assert_eq!( 2, child  .spawned_total() );
+	assert_eq!( 3, nursery.active_tasks()  );
+	assert_eq!( 2, child  .active_tasks()  );
+
+	drop( nursery );
+	drop( child   );
+
+	let outputs: Vec<usize> = output.by_ref().collect().await;
+
+	assert_eq!( 3, outputs.len()            );
+	assert_eq!( 3, output.completed_total() );
+	assert_eq!( 0, output.active_tasks()    );
+
+	Ok(())
+}
@@ -0,0 +1,167 @@
+#![ cfg( feature = "metrics" ) ]
+
+// Tested:
+//
+// ✔ The observer is told about spawns, polls and how each task ended.
+// ✔ Tasks dropped with the NurseryStream are reported as dropped.
+// ✔ Panics caught by the PanicPolicy are reported as panicked.
+// ✔ Child nurseries inherit the observer.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+use std::sync::Mutex;
+
+
+
+#[ derive( Default ) ]
+//
+struct Recorder
+{
+	spawned: Mutex< Vec<TaskId>            > ,
+	polls  : AtomicUsize                     ,
+	ended  : Mutex< Vec<(TaskId, TaskEnd)> > ,
+}
+
+
+impl NurseryObserver for Recorder
+{
+	fn on_spawn( &self, id: TaskId )
+	{
+		self.spawned.lock().unwrap().push( id );
+	}
+
+
+	fn on_poll( &self, _duration: Duration )
+	{
+		self.polls.fetch_add( 1, SeqCst );
+	}
+
+
+	fn on_end( &self, id: TaskId, end: TaskEnd, _latency: Duration )
+	{
+		self.ended.lock().unwrap().push( (id, end) );
+	}
+}
+
+
+impl Recorder
+{
+	fn ended( &self ) -> Vec<TaskEnd>
+	{
+		let mut ended = self.ended.lock().unwrap().clone();
+		ended.sort_unstable_by_key( |(id, _)| *id );
+
+		ended.into_iter().map( |(_, end)| end ).collect()
+	}
+}
+
+
+
+// The observer is told about spawns, polls and how each task ended.
+//
+#[ async_std::test ]
+//
+async fn metrics_events() -> DynResult<()>
+{
+	let recorder              = Arc::new( Recorder::default() );
+	let (mut nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.set_observer( recorder.clone() );
+
+	let a = nursery.nurse_with_id( async { 1 } )?;
+	let b = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(10) ).await; 2 } )?;
+
+	drop( nursery );
+
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( 2                            , outputs.len()                     );
+	assert_eq!( vec![ a, b ]                 , *recorder.spawned.lock().unwrap() );
+	assert_eq!( vec![ TaskEnd::Completed; 2 ], recorder.ended()                  );
+	assert!   ( recorder.polls.load( SeqCst ) >= 3                               );
+
+	Ok(())
+}
+
+
+
+// Tasks dropped with the NurseryStream are reported as dropped.
+//
+#[ async_std::test ]
+//
+async fn metrics_dropped() -> DynResult<()>
+{
+	let recorder                  = Arc::new( Recorder::default() );
+	let (mut nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.set_observer( recorder.clone() );
+
+	nursery.nurse( async { 1 } )?;
+	nursery.nurse( futures::future::pending() )?;
+
+	assert_eq!( Some(1), output.next().await );
+
+	drop( output );
+
+	assert_eq!( vec![ TaskEnd::Completed, TaskEnd::Dropped ], recorder.ended() );
+
+	Ok(())
+}
+
+
+
+// Panics caught by the PanicPolicy are reported as panicked.
+//
+#[ async_std::test ]
+//
+async fn metrics_panicked() -> DynResult<()>
+{
+	let recorder                  = Arc::new( Recorder::default() );
+	let (mut nursery, mut output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery
+		.set_panic_policy( PanicPolicy::CancelSiblings )
+		.set_observer    ( recorder.clone()            )
+	;
+
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	drop( nursery );
+
+	assert_eq!( None, output.next().await );
+	assert!   ( output.take_panic().is_some() );
+
+	assert_eq!( vec![ TaskEnd::Panicked ], recorder.ended() );
+
+	Ok(())
+}
+
+
+
+// Child nurseries inherit the observer.
+//
+#[ async_std::test ]
+//
+async fn metrics_child() -> DynResult<()>
+{
+	let recorder              = Arc::new( Recorder::default() );
+	let (mut nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.set_observer( recorder.clone() );
+
+	let child = nursery.child()?;
+
+	nursery.nurse( async { 1 } )?;
+	child  .nurse( async { 2 } )?;
+
+	drop( nursery );
+	drop( child   );
+
+	output.await;
+
+	assert_eq!( vec![ TaskEnd::Completed; 2 ], recorder.ended() );
+
+	Ok(())
+}
@@ -5,6 +5,7 @@
 // ✔ Verify close_nursery works.
 // - test Sink impl.
 // ✔ Verify traits are all available on Nursery.
+// ✔ NurseryStream is Send and Sync.
 //
 #![ cfg(not( target_arch = "wasm32" )) ]
 
@@ -50,3 +51,16 @@ use common::{ *, import::* };
 
 	Ok(())
 }
+
+
+
+// NurseryStream is Send and Sync.
+//
+#[test] fn stream_send_sync()
+{
+	fn assert_send_sync<T: Send + Sync>() {}
+
+	assert_send_sync::< NurseryStream<u32>          >();
+	assert_send_sync::< Nursery<AsyncStd, u32>      >();
+	assert_send_sync::< TryNurseryStream<u32, ()>  >();
+}
@@ -0,0 +1,158 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Outputs come in spawn order.
+// ✔ Keep spawning while consuming the ordered stream.
+// ✔ Tasks with a TaskHandle don't block the order.
+// ✔ Tasks the stream already received keep their place when it becomes ordered.
+// ✔ The limit, the ordering and the deadline combine.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+use futures::FutureExt;
+
+
+async fn slow( i: u64 ) -> u64
+{
+	Delay::new( Duration::from_millis( 5 * (5-i) ) ).await;
+	i
+}
+
+
+
+// Outputs come in spawn order.
+//
+#[ async_std::test ]
+//
+async fn ordered() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new_ordered( AsyncStd );
+
+	for i in 0..5
+	{
+		nursery.nurse( slow(i) )?;
+	}
+
+	drop(nursery);
+
+	let outputs: Vec<u64> = output.collect().await;
+
+	assert_eq!( vec![ 0, 1, 2, 3, 4 ], outputs );
+
+	Ok(())
+}
+
+
+
+// Keep spawning while consuming the ordered stream.
+//
+#[ async_std::test ]
+//
+async fn ordered_mixed() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new_ordered( AsyncStd );
+
+	nursery.nurse( slow(1) )?;
+	nursery.nurse( slow(4) )?;
+
+	assert_eq!( Some(1), output.next().await );
+
+	nursery.nurse( slow(2) )?;
+	nursery.nurse( slow(3) )?;
+
+	drop(nursery);
+
+	let outputs: Vec<u64> = output.collect().await;
+
+	assert_eq!( vec![ 4, 2, 3 ], outputs );
+
+	Ok(())
+}
+
+
+
+// Tasks with a TaskHandle don't block the order.
+//
+#[ async_std::test ]
+//
+async fn ordered_handle() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new_ordered( AsyncStd );
+
+	nursery.nurse( slow(0) )?;
+	let handle = nursery.nurse_with_handle( slow(1) )?;
+	nursery.nurse( slow(2) )?;
+
+	drop(nursery);
+
+	let outputs: Vec<u64> = output.collect().await;
+
+	assert_eq!( vec![ 0, 2 ], outputs      );
+	assert_eq!( Some(1)     , handle.await );
+
+	Ok(())
+}
+
+
+
+// Tasks the stream already received keep their place when it becomes ordered.
+//
+#[ async_std::test ]
+//
+async fn ordered_later() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( slow(2) )?;
+	nursery.nurse( slow(3) )?;
+
+	// Let the stream receive the tasks.
+	//
+	assert!( output.next().now_or_never().is_none() );
+
+	output.set_ordered();
+
+	nursery.nurse( async { 4 } )?;
+
+	drop(nursery);
+
+	let outputs: Vec<u64> = output.collect().await;
+
+	assert_eq!( vec![ 2, 3, 4 ], outputs );
+
+	Ok(())
+}
+
+
+
+// The limit, the ordering and the deadline combine.
+//
+#[ async_std::test ]
+//
+async fn ordered_bounded_deadline() -> DynResult<()>
+{
+	let (mut nursery, mut output) = Nursery::< _, Result<u64, NurseErr> >::new( AsyncStd );
+
+	nursery.set_bound( 2 ).set_deadline( Duration::from_millis(50) );
+	output.set_ordered();
+
+	nursery.nurse( async { Delay::new( Duration::from_millis(20) ).await; Ok(1) } )?;
+	nursery.nurse( futures::future::pending() )?;
+
+	assert_eq!( Err( NurseErr::Full ), nursery.nurse( async { Ok(3) } ) );
+
+	// Waits for the first task to free it's slot.
+	//
+	nursery.nurse_async( async { Ok(3) } ).await?;
+
+	drop(nursery);
+
+	let outputs: Vec<_> = output.collect().await;
+
+	assert_eq!( vec![ Ok(1), Err( NurseErr::Timeout ), Ok(3) ], outputs );
+
+	Ok(())
+}
@@ -0,0 +1,232 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Completed tasks yield their output.
+// ✔ Panics are reported without a PanicPolicy, and don't resume on the consumer.
+// ✔ Panics of tasks with a TaskHandle are reported without a PanicPolicy, and the handle resolves to None.
+// ✔ Panics are reported with PanicPolicy::Propagate, and the stream continues.
+// ✔ With PanicPolicy::CancelSiblings, the other tasks are reported as cancelled.
+// ✔ Tasks dropped by their timeout or the deadline of the nursery are reported as timed out.
+// ✔ Background tasks dropped by the stream are reported as cancelled.
+// ✔ Tasks of child nurseries are reported.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+/// Collect the outcomes, sorted by task id, with the outputs and payloads left out.
+//
+async fn outcomes<Out: 'static>( stream: NurseryStream<Out> ) -> Vec<(TaskId, &'static str)>
+{
+	let mut outcomes: Vec<_> = stream.outcomes().map( |(id, _, outcome)|
+	{
+		let kind = match outcome
+		{
+			TaskOutcome::Completed(_) => "completed",
+			TaskOutcome::Panicked (_) => "panicked" ,
+			TaskOutcome::Cancelled    => "cancelled",
+			TaskOutcome::TimedOut     => "timed out",
+		};
+
+		(id, kind)
+
+	}).collect().await;
+
+	outcomes.sort_unstable();
+	outcomes
+}
+
+
+
+// Completed tasks yield their output.
+//
+#[ async_std::test ]
+//
+async fn outcome_completed() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let a = nursery.nurse_with_id( async { 5 } )?;
+
+	drop( nursery );
+
+	let outcomes: Vec<_> = output.outcomes().collect().await;
+
+	assert!( matches!( outcomes[..], [ (id, None, TaskOutcome::Completed(5)) ] if id == a ) );
+
+	Ok(())
+}
+
+
+
+// Panics are reported without a PanicPolicy, and don't resume on the consumer.
+//
+#[ async_std::test ]
+//
+async fn outcome_panic_no_policy() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	let a = nursery.nurse_with_id( async { panic!( "boom" ) } )?;
+	let b = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(10) ).await; 2 } )?;
+
+	drop( nursery );
+
+	assert_eq!( vec![ (a, "panicked"), (b, "completed") ], outcomes( output ).await );
+
+	Ok(())
+}
+
+
+
+// Panics of tasks with a TaskHandle are reported without a PanicPolicy, and the handle resolves to None.
+//
+#[ async_std::test ]
+//
+async fn outcome_panic_handle() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	let handle = nursery.nurse_with_handle( async { panic!( "boom" ) } )?;
+	let a      = handle.id();
+	let b      = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(10) ).await; 2 } )?;
+
+	drop( nursery );
+
+	assert_eq!( vec![ (a, "panicked"), (b, "completed") ], outcomes( output ).await );
+	assert_eq!( None, handle.await );
+
+	Ok(())
+}
+
+
+
+// Panics are reported with PanicPolicy::Propagate, and the stream continues.
+//
+#[ async_std::test ]
+//
+async fn outcome_panic_propagate() -> DynResult<()>
+{
+	let (mut nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::Propagate );
+
+	nursery.nurse( async { panic!( "boom" ) } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(10) ).await; 2 } )?;
+
+	drop( nursery );
+
+	let outcomes: Vec<_> = output.outcomes().map( |(_, _, outcome)| outcome ).collect().await;
+
+	assert_eq!( 2, outcomes.len() );
+
+	match &outcomes[0]
+	{
+		TaskOutcome::Panicked( payload ) => assert_eq!( Some( &"boom" ), payload.downcast_ref::<&str>() ),
+		other                            => panic!( "unexpected outcome: {other:?}" ),
+	}
+
+	assert!( matches!( outcomes[1], TaskOutcome::Completed(2) ) );
+
+	Ok(())
+}
+
+
+
+// With PanicPolicy::CancelSiblings, the other tasks are reported as cancelled.
+//
+#[ async_std::test ]
+//
+async fn outcome_cancel_siblings() -> DynResult<()>
+{
+	let (mut nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::CancelSiblings );
+
+	let a = nursery.nurse_with_id( futures::future::pending() )?;
+	let b = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(10) ).await; panic!( "boom" ) } )?;
+	let c = nursery.nurse_with_id( futures::future::pending() )?;
+
+	drop( nursery );
+
+	assert_eq!( vec![ (a, "cancelled"), (b, "panicked"), (c, "cancelled") ], outcomes( output ).await );
+
+	Ok(())
+}
+
+
+
+// Tasks dropped by their timeout or the deadline of the nursery are reported as timed out.
+//
+#[ async_std::test ]
+//
+async fn outcome_timed_out() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, NurseErr>>::with_deadline( AsyncStd, Duration::from_millis(50) );
+
+	let a = nursery.nurse_with_id( async { Ok(1) } )?;
+	let b = nursery.task().timeout( Duration::from_millis(5) ).spawn( futures::future::pending() )?;
+	let c = nursery.nurse_with_id( futures::future::pending() )?;
+
+	// A task that returns NurseErr::Timeout itself did complete.
+	//
+	let d = nursery.nurse_with_id( async { Err( NurseErr::Timeout ) } )?;
+
+	nursery.nurse_timeout( Duration::from_millis(5), futures::future::pending() )?;
+
+	drop( nursery );
+
+	let outcomes = outcomes( output ).await;
+
+	assert_eq!( vec![ (a, "completed"), (b, "timed out"), (c, "timed out"), (d, "completed") ], outcomes[..4] );
+	assert_eq!( "timed out", outcomes[4].1 );
+
+	Ok(())
+}
+
+
+
+// Background tasks dropped by the stream are reported as cancelled.
+//
+#[ async_std::test ]
+//
+async fn outcome_background() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let a = nursery.nurse_with_id( async { Delay::new( Duration::from_millis(10) ).await; 1 } )?;
+	let b = nursery.task().critical( false ).spawn( futures::future::pending() )?;
+
+	assert_eq!( vec![ (a, "completed"), (b, "cancelled") ], outcomes( output ).await );
+
+	Ok(())
+}
+
+
+
+// Tasks of child nurseries are reported.
+//
+#[ async_std::test ]
+//
+async fn outcome_child() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+	let child             = nursery.child()?;
+
+	let a = nursery.nurse_with_id( async { 1 } )?;
+	let b = child  .nurse_with_id( async { panic!( "boom" ) } )?;
+
+	drop( nursery );
+	drop( child   );
+
+	let mut outcomes = outcomes( output ).await;
+	outcomes.sort_unstable_by_key( |(_, kind)| *kind );
+
+	assert_eq!( vec![ (a, "completed"), (b, "panicked") ], outcomes );
+
+	Ok(())
+}
@@ -0,0 +1,196 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ PanicPolicy::Propagate resumes the panic when polling the NurseryStream.
+// ✔ PanicPolicy::Map turns the panic into an output.
+// ✔ PanicPolicy::CancelSiblings cancels the nursery and ends the stream.
+// ✔ The policies behave the same on TokioCt and TokioTp.
+// ✔ A task that panicked gives back it's slot on a bounded nursery before the stream drops it.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+use futures::FutureExt;
+use futures::future::{ select, Either };
+use std::panic::AssertUnwindSafe;
+
+
+
+// PanicPolicy::Propagate resumes the panic when polling the NurseryStream.
+//
+#[ async_std::test ]
+//
+async fn panic_propagate() -> DynResult<()>
+{
+	let (mut nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::Propagate );
+
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	drop(nursery);
+
+	let payload = AssertUnwindSafe( output.next() ).catch_unwind().await.expect_err( "panic to propagate" );
+
+	assert_eq!( Some( &"boom" ), payload.downcast_ref::<&str>() );
+
+	Ok(())
+}
+
+
+
+// A task that panicked gives back it's slot on a bounded nursery before the stream drops it.
+//
+#[ async_std::test ]
+//
+async fn panic_bounded() -> DynResult<()>
+{
+	let (mut nursery, _output) = Nursery::bounded( AsyncStd, 1 );
+
+	nursery.set_panic_policy( PanicPolicy::CancelSiblings );
+
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	// The stream isn't polled, so it doesn't drop the task.
+	//
+	let timeout = Delay::new( Duration::from_secs(5) );
+
+	assert!( matches!( select( Box::pin( nursery.nurse_async( async { 1 } ) ), timeout ).await, Either::Left( (Ok(()), _) ) ) );
+
+	Ok(())
+}
+
+
+
+// PanicPolicy::Map turns the panic into an output.
+//
+#[ async_std::test ]
+//
+async fn panic_map() -> DynResult<()>
+{
+	let (mut nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::map( |_| 0 ) );
+
+	nursery.nurse( async { 5 } )?;
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	drop(nursery);
+
+	let mut outputs: Vec<usize> = output.collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![0, 5], outputs );
+
+	Ok(())
+}
+
+
+
+// PanicPolicy::CancelSiblings cancels the nursery and ends the stream.
+//
+#[ async_std::test ]
+//
+async fn panic_cancel_siblings() -> DynResult<()>
+{
+	let (mut nursery, mut output) = Nursery::new( AsyncStd );
+	let token = nursery.cancel_token();
+	let ran   = Arc::new( AtomicBool::new( false ) );
+	let ran2  = ran.clone();
+
+	nursery.set_panic_policy( PanicPolicy::CancelSiblings );
+
+	nursery.nurse( async move { Delay::new( Duration::from_secs(10) ).await; ran2.store( true, SeqCst ); } )?;
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	assert_eq!( None, output.next().await );
+
+	assert!( token.is_cancelled() );
+	assert!( !ran.load( SeqCst ) );
+	assert!( nursery.nurse( async {} ).is_err() );
+
+	let payload = output.take_panic().expect( "panic payload" );
+
+	assert_eq!( Some( &"boom" ), payload.downcast_ref::<&str>() );
+	assert!( output.take_panic().is_none() );
+
+	Ok(())
+}
+
+
+
+// The policies behave the same on TokioCt and TokioTp. Bindgen is left out, as panics abort on wasm
+// and can't be caught.
+//
+async fn policies<S>( exec: S ) -> DynResult<()>
+
+	where S: SpawnHandle<usize> + Clone + 'static
+{
+	// Propagate
+	//
+	let (mut nursery, mut output) = Nursery::new( exec.clone() );
+
+	nursery.set_panic_policy( PanicPolicy::Propagate );
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	drop(nursery);
+
+	let payload = AssertUnwindSafe( output.next() ).catch_unwind().await.expect_err( "panic to propagate" );
+
+	assert_eq!( Some( &"boom" ), payload.downcast_ref::<&str>() );
+
+
+	// Map
+	//
+	let (mut nursery, output) = Nursery::new( exec.clone() );
+
+	nursery.set_panic_policy( PanicPolicy::map( |_| 0 ) );
+	nursery.nurse( async { 5 } )?;
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	drop(nursery);
+
+	let mut outputs: Vec<usize> = output.collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![0, 5], outputs );
+
+
+	// CancelSiblings
+	//
+	let (mut nursery, mut output) = Nursery::new( exec );
+	let token = nursery.cancel_token();
+
+	nursery.set_panic_policy( PanicPolicy::CancelSiblings );
+	nursery.nurse( async { Delay::new( Duration::from_secs(10) ).await; 1 } )?;
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	assert_eq!( None, output.next().await );
+	assert!   ( token.is_cancelled()      );
+
+	let payload = output.take_panic().expect( "panic payload" );
+
+	assert_eq!( Some( &"boom" ), payload.downcast_ref::<&str>() );
+
+	Ok(())
+}
+
+
+
+#[ test ] fn panic_tokio_ct() -> DynResult<()>
+{
+	let exec = TokioCt::new()?;
+
+	exec.block_on( policies( exec.clone() ) )
+}
+
+
+
+#[ test ] fn panic_tokio_tp() -> DynResult<()>
+{
+	let exec = TokioTp::new()?;
+
+	exec.block_on( policies( exec.clone() ) )
+}
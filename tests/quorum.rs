@@ -0,0 +1,155 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ quorum resolves once n tasks succeeded, with the failures seen so far, and drops the stragglers.
+// ✔ quorum gives up once the remaining tasks can't make up n successes.
+// ✔ quorum waits for new tasks while the nursery is open.
+// ✔ quorum(0) is reached right away.
+// ✔ Tasks with a TaskHandle don't count towards the tasks that can still succeed.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+async fn replica( delay: u64, out: Result<usize, &'static str> ) -> Result<usize, &'static str>
+{
+	Delay::new( Duration::from_millis( delay ) ).await;
+	out
+}
+
+
+
+// quorum resolves once n tasks succeeded, with the failures seen so far, and drops the stragglers.
+//
+#[ async_std::test ]
+//
+async fn quorum_reached() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( replica( 1 , Err( "down" ) ) )?;
+	nursery.nurse( replica( 10, Ok(1)         ) )?;
+	nursery.nurse( replica( 20, Ok(2)         ) )?;
+	nursery.nurse( futures::future::pending()   )?;
+
+	drop( nursery );
+
+	let report = output.quorum( 2 ).await;
+
+	assert!   ( report.reached                   );
+	assert_eq!( vec![ 1, 2   ], report.succeeded );
+	assert_eq!( vec![ "down" ], report.failed    );
+
+	Ok(())
+}
+
+
+
+// quorum gives up once the remaining tasks can't make up n successes.
+//
+#[ async_std::test ]
+//
+async fn quorum_impossible() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( replica( 1 , Err( "down" ) ) )?;
+	nursery.nurse( replica( 10, Err( "down" ) ) )?;
+	nursery.nurse( futures::future::pending()   )?;
+
+	let token = nursery.cancel_token();
+
+	drop( nursery );
+
+	let report = output.quorum( 2 ).await;
+
+	assert!   ( !report.reached                       );
+	assert!   ( report.succeeded.is_empty()           );
+	assert_eq!( vec![ "down", "down" ], report.failed );
+	assert!   ( token.is_cancelled()                  );
+
+	Ok(())
+}
+
+
+
+// quorum waits for new tasks while the nursery is open.
+//
+#[ async_std::test ]
+//
+async fn quorum_open() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( replica( 1, Err( "down" ) ) )?;
+
+	let late = nursery.clone();
+
+	nursery.nurse( async move
+	{
+		Delay::new( Duration::from_millis(10) ).await;
+		late.nurse( replica( 1, Ok(2) ) ).expect( "spawn" );
+
+		Ok(1)
+	})?;
+
+	drop( nursery );
+
+	let report = output.quorum( 2 ).await;
+
+	assert!   ( report.reached                   );
+	assert_eq!( vec![ 1, 2   ], report.succeeded );
+	assert_eq!( vec![ "down" ], report.failed    );
+
+	Ok(())
+}
+
+
+
+// quorum(0) is reached right away.
+//
+#[ async_std::test ]
+//
+async fn quorum_zero() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( futures::future::pending::< Result<usize, ()> >() )?;
+
+	let report = output.quorum( 0 ).await;
+
+	assert!   ( report.reached              );
+	assert!   ( report.succeeded.is_empty() );
+	assert_eq!( 0, nursery.active_tasks()   );
+
+	Ok(())
+}
+
+
+
+// Tasks with a TaskHandle don't count towards the tasks that can still succeed.
+//
+#[ async_std::test ]
+//
+async fn quorum_handle() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let _handle = nursery.nurse_with_handle( futures::future::pending::< Result<usize, &str> >() )?;
+
+	nursery.nurse( replica( 1, Err( "down" ) ) )?;
+
+	drop( nursery );
+
+	let report = output.quorum( 1 ).await;
+
+	assert!   ( !report.reached              );
+	assert!   ( report.succeeded.is_empty()  );
+	assert_eq!( vec![ "down" ], report.failed );
+
+	Ok(())
+}
@@ -0,0 +1,116 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ race resolves with the output of the task that finishes first and drops the others.
+// ✔ race closes and cancels the nursery.
+// ✔ race resolves to None when no task was spawned.
+// ✔ first_ok skips errors and resolves with the first Ok.
+// ✔ first_ok resolves to all errors if no task succeeds.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// race resolves with the output of the task that finishes first and drops the others.
+//
+#[ async_std::test ]
+//
+async fn race_first() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let slow_done         = Arc::new( AtomicBool::new( false ) );
+	let slow_done2        = slow_done.clone();
+
+	nursery.nurse( async move { Delay::new( Duration::from_millis(50) ).await; slow_done2.store( true, SeqCst ); 1 } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(5) ).await; 2 } )?;
+
+	assert_eq!( Some(2), output.race().await );
+	assert_eq!( 0, nursery.active_tasks()    );
+
+	Delay::new( Duration::from_millis(80) ).await;
+
+	assert!( !slow_done.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// race closes and cancels the nursery.
+//
+#[ async_std::test ]
+//
+async fn race_closes() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { 1 } )?;
+
+	assert_eq!( Some(1), output.race().await );
+
+	assert!   ( nursery.cancel_token().is_cancelled()                 );
+	assert_eq!( Err( NurseErr::Closed ), nursery.nurse( async { 2 } ) );
+
+	Ok(())
+}
+
+
+
+// race resolves to None when no task was spawned.
+//
+#[ async_std::test ]
+//
+async fn race_empty() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	drop( nursery );
+
+	assert_eq!( None, output.race().await );
+
+	Ok(())
+}
+
+
+
+// first_ok skips errors and resolves with the first Ok.
+//
+#[ async_std::test ]
+//
+async fn first_ok() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { Err( "refused" ) } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(10) ).await; Ok(2) } )?;
+	nursery.nurse( futures::future::pending() )?;
+
+	assert_eq!( Ok(2), output.first_ok().await );
+	assert_eq!( 0, nursery.active_tasks()      );
+
+	Ok(())
+}
+
+
+
+// first_ok resolves to all errors if no task succeeds.
+//
+#[ async_std::test ]
+//
+async fn first_ok_all_err() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, usize>>::new( AsyncStd );
+
+	nursery.nurse( async { Err(1) } )?;
+	nursery.nurse( async { Delay::new( Duration::from_millis(10) ).await; Err(2) } )?;
+
+	drop( nursery );
+
+	assert_eq!( Err( vec![ 1, 2 ] ), output.first_ok().await );
+
+	Ok(())
+}
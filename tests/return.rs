@@ -5,6 +5,8 @@
 // ✔ return values
 // ✔ return values - single thread.
 // ✔ early return on error.
+// ✔ TryNurseryStream collects all outputs.
+// ✔ TryNurseryStream returns the first error and drops the other tasks.
 // - return catch_unwind
 //
 #![ cfg(not( target_arch = "wasm32" )) ]
@@ -95,3 +97,48 @@ async fn early_return_error() -> DynSendResult<()>
 
 	Ok(())
 }
+
+
+
+// TryNurseryStream collects all outputs.
+//
+#[ async_std::test ]
+//
+async fn try_nursery_ok() -> DynSendResult<()>
+{
+	let (nursery, output) = Nursery::new_try( AsyncStd );
+
+	nursery.nurse( async { DynSendResult::Ok(5) } )?;
+	nursery.nurse( async { DynSendResult::Ok(5) } )?;
+
+	drop(nursery);
+
+	let outputs = output.await?;
+
+	assert_eq!( vec![5, 5], outputs );
+
+	Ok(())
+}
+
+
+
+// TryNurseryStream returns the first error and drops the other tasks.
+//
+#[ async_std::test ]
+//
+async fn try_nursery_err() -> DynSendResult<()>
+{
+	let (nursery, output) = Nursery::new_try( AsyncStd );
+	let token             = nursery.cancel_token();
+
+	nursery.nurse( slow()  )?;
+	nursery.nurse( wrong() )?;
+
+	// The nursery is still alive, but the error ends it anyway.
+	//
+	assert!( output.await.is_err() );
+	assert!( token.is_cancelled()  );
+	assert!( nursery.nurse( slow() ).is_err() );
+
+	Ok(())
+}
@@ -0,0 +1,125 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Scoped tasks can borrow from the enclosing stack frame.
+// ✔ Scoped tasks can spawn further tasks, the scope waits for all of them.
+// ✔ 'static tasks spawned through NurseExt are joined as well.
+// ✔ Spawning on the scope after it ended returns NurseErr::Closed.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Scoped tasks can borrow from the enclosing stack frame.
+//
+#[ async_std::test ]
+//
+async fn scope_borrow() -> DynResult<()>
+{
+	let data    = vec![ 1, 2, 3 ];
+	let counter = AtomicUsize::new( 0 );
+	let data    = &data;
+	let counter = &counter;
+
+	let (out, mut outputs) = async_nursery::scope( AsyncStd, |s| async move
+	{
+		for x in data
+		{
+			s.nurse_scoped( async move { counter.fetch_add( 1, SeqCst ); x * 2 } )?;
+		}
+
+		Ok::<_, NurseErr>( "body" )
+
+	}).await;
+
+	outputs.sort_unstable();
+
+	assert_eq!( "body"       , out?                 );
+	assert_eq!( vec![2, 4, 6], outputs              );
+	assert_eq!( 3            , counter.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// Scoped tasks can spawn further tasks, the scope waits for all of them.
+//
+#[ async_std::test ]
+//
+async fn scope_nested() -> DynResult<()>
+{
+	let counter = AtomicUsize::new( 0 );
+	let counter = &counter;
+
+	let ((), outputs) = async_nursery::scope( AsyncStd, |s| async move
+	{
+		let s2 = s.clone();
+
+		s.nurse_scoped( async move
+		{
+			Delay::new( Duration::from_millis(10) ).await;
+
+			s2.nurse_scoped( async move
+			{
+				Delay::new( Duration::from_millis(10) ).await;
+				counter.fetch_add( 1, SeqCst );
+
+			}).expect( "nurse_scoped" );
+
+		}).expect( "nurse_scoped" );
+
+	}).await;
+
+	assert_eq!( 2, outputs.len()          );
+	assert_eq!( 1, counter.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// 'static tasks spawned through NurseExt are joined as well.
+//
+#[ async_std::test ]
+//
+async fn scope_static() -> DynResult<()>
+{
+	let (res, mut outputs) = async_nursery::scope( AsyncStd, |s| async move
+	{
+		s.nurse( async { Delay::new( Duration::from_millis(10) ).await; 1 } )?;
+		s.nurse_scoped( async { 2 } )?;
+
+		Ok::<_, NurseErr>(())
+
+	}).await;
+
+	outputs.sort_unstable();
+
+	res?;
+	assert_eq!( vec![1, 2], outputs );
+
+	Ok(())
+}
+
+
+
+// Spawning on the scope after it ended returns NurseErr::Closed.
+//
+#[ async_std::test ]
+//
+async fn scope_closed() -> DynResult<()>
+{
+	let (s, outputs) = async_nursery::scope( AsyncStd, |s| async move { s } ).await;
+
+	assert!( outputs.is_empty() );
+
+	assert_eq!( Err( NurseErr::Closed ), s.nurse_scoped( async { 1 } ) );
+	assert_eq!( Err( NurseErr::Closed ), s.nurse       ( async { 1 } ) );
+
+	Ok(())
+}
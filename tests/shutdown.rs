@@ -0,0 +1,73 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Tasks that respect the token finish, others get dropped after the grace period.
+// ✔ Shutdown resolves early when all tasks finish within the grace period.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Tasks that respect the token finish, others get dropped after the grace period.
+//
+#[ async_std::test ]
+//
+async fn shutdown_drops_stragglers() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let dropped           = Arc::new( AtomicBool::new( false ) );
+	let token             = nursery.cancel_token();
+	let flag              = dropped.clone();
+
+	let first = nursery.nurse_with_id( async move { token.cancelled().await; 1 } )?;
+
+	let second = nursery.nurse_with_id( async move
+	{
+		// Detects being dropped.
+		//
+		struct Guard( Arc<AtomicBool> );
+		impl Drop for Guard { fn drop( &mut self ) { self.0.store( true, SeqCst ); } }
+
+		let _guard = Guard( flag );
+
+		Delay::new( Duration::from_secs(10) ).await;
+		2
+	})?;
+
+	let report = output.shutdown( Duration::from_millis(50), &nursery ).await;
+
+	assert_eq!( report.finished, vec![ (first, 1) ] );
+	assert_eq!( report.dropped , vec![ second     ] );
+
+	// Give async-std a moment to actually drop the task.
+	//
+	Delay::new( Duration::from_millis(20) ).await;
+	assert!( dropped.load( SeqCst ) );
+
+	Ok(())
+}
+
+
+
+// Shutdown resolves early when all tasks finish within the grace period.
+//
+#[ async_std::test ]
+//
+async fn shutdown_early() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let token             = nursery.cancel_token();
+
+	let id = nursery.nurse_with_id( async move { token.cancelled().await; 1 } )?;
+
+	let report = AsyncStd.timeout( Duration::from_secs(5), output.shutdown( Duration::from_secs(10), &AsyncStd ) ).await?;
+
+	assert_eq!( report.finished, vec![ (id, 1) ] );
+	assert!   ( report.dropped.is_empty()        );
+
+	Ok(())
+}
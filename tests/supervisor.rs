@@ -0,0 +1,243 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ A task that fails is restarted until it succeeds.
+// ✔ A task that panics is restarted.
+// ✔ A factory that panics is restarted.
+// ✔ When the restart limit is reached, the failure is yielded and the other tasks are stopped.
+// ✔ With one-for-all, the other tasks are restarted when one fails.
+// ✔ Supervising after the supervisor gave up returns NurseErr::Closed.
+// ✔ Failure implements Error and Display.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+fn policy( strategy: RestartPolicy ) -> RestartPolicy
+{
+	strategy
+		.max_restarts( 2, Duration::from_secs(10) )
+		.backoff( Duration::from_millis(1), Duration::from_millis(5) )
+}
+
+
+
+// A task that fails is restarted until it succeeds.
+//
+#[ async_std::test ]
+//
+async fn restart_error() -> DynResult<()>
+{
+	let (supervisor, output) = Supervisor::new( AsyncStd, policy( RestartPolicy::one_for_one() ) );
+	let runs                 = Arc::new( AtomicUsize::new( 0 ) );
+	let runs2                = runs.clone();
+
+	supervisor.supervise( move ||
+	{
+		let run = runs2.fetch_add( 1, SeqCst );
+
+		async move { if run < 2 { Err( "fail" ) } else { Ok(()) } }
+
+	})?;
+
+	drop( supervisor );
+
+	let outputs: Vec<_> = output.collect().await;
+
+	assert!   ( matches!( outputs[..], [ Ok(()) ] ) );
+	assert_eq!( 3, runs.load( SeqCst )              );
+
+	Ok(())
+}
+
+
+
+// A task that panics is restarted.
+//
+#[ async_std::test ]
+//
+async fn restart_panic() -> DynResult<()>
+{
+	let (supervisor, output) = Supervisor::<_, ()>::new( AsyncStd, policy( RestartPolicy::one_for_one() ) );
+	let runs                 = Arc::new( AtomicUsize::new( 0 ) );
+	let runs2                = runs.clone();
+
+	supervisor.supervise( move ||
+	{
+		let run = runs2.fetch_add( 1, SeqCst );
+
+		async move { if run == 0 { panic!( "boom" ) } Ok(()) }
+
+	})?;
+
+	drop( supervisor );
+
+	let outputs: Vec<_> = output.collect().await;
+
+	assert!   ( matches!( outputs[..], [ Ok(()) ] ) );
+	assert_eq!( 2, runs.load( SeqCst )              );
+
+	Ok(())
+}
+
+
+
+// A factory that panics is restarted.
+//
+#[ async_std::test ]
+//
+async fn restart_factory_panic() -> DynResult<()>
+{
+	let (supervisor, output) = Supervisor::<_, ()>::new( AsyncStd, policy( RestartPolicy::one_for_one() ) );
+	let runs                 = Arc::new( AtomicUsize::new( 0 ) );
+	let runs2                = runs.clone();
+
+	supervisor.supervise( move ||
+	{
+		if runs2.fetch_add( 1, SeqCst ) == 0 { panic!( "boom" ) }
+
+		async { Ok(()) }
+
+	})?;
+
+	drop( supervisor );
+
+	let outputs: Vec<_> = output.collect().await;
+
+	assert!   ( matches!( outputs[..], [ Ok(()) ] ) );
+	assert_eq!( 2, runs.load( SeqCst )              );
+
+	Ok(())
+}
+
+
+
+// When the restart limit is reached, the failure is yielded and the other tasks are stopped.
+//
+#[ async_std::test ]
+//
+async fn give_up() -> DynResult<()>
+{
+	let (supervisor, output) = Supervisor::new( AsyncStd, policy( RestartPolicy::one_for_one() ) );
+	let runs                 = Arc::new( AtomicUsize::new( 0 ) );
+	let runs2                = runs.clone();
+
+	supervisor.supervise( futures::future::pending )?;
+
+	supervisor.supervise( move ||
+	{
+		runs2.fetch_add( 1, SeqCst );
+
+		async { Err( "fail" ) }
+
+	})?;
+
+	drop( supervisor );
+
+	let outputs: Vec<_> = output.collect().await;
+
+	assert_eq!( 2, outputs.len()       );
+	assert_eq!( 3, runs.load( SeqCst ) );
+
+	assert!( outputs.iter().any( |out| matches!( out, Err( Failure::Error( "fail" ) ) ) ) );
+	assert!( outputs.iter().any( |out| matches!( out, Err( Failure::Stopped         ) ) ) );
+
+	Ok(())
+}
+
+
+
+// With one-for-all, the other tasks are restarted when one fails.
+//
+#[ async_std::test ]
+//
+async fn one_for_all() -> DynResult<()>
+{
+	let (supervisor, output) = Supervisor::new( AsyncStd, policy( RestartPolicy::one_for_all() ) );
+	let starts               = Arc::new( AtomicUsize::new( 0 ) );
+	let starts2              = starts.clone();
+	let fails                = Arc::new( AtomicUsize::new( 0 ) );
+	let fails2               = fails.clone();
+
+	// Runs until the other task has stopped failing.
+	//
+	supervisor.supervise( move ||
+	{
+		starts2.fetch_add( 1, SeqCst );
+		let fails = fails2.clone();
+
+		async move
+		{
+			while fails.load( SeqCst ) < 2
+			{
+				Delay::new( Duration::from_millis(1) ).await;
+			}
+
+			Ok::<_, &str>(())
+		}
+	})?;
+
+	supervisor.supervise( move ||
+	{
+		let fail = fails.fetch_add( 1, SeqCst );
+
+		async move { if fail < 1 { Err( "fail" ) } else { Ok(()) } }
+
+	})?;
+
+	drop( supervisor );
+
+	let outputs: Vec<_> = output.collect().await;
+
+	assert!   ( matches!( outputs[..], [ Ok(()), Ok(()) ] ) );
+	assert_eq!( 2, starts.load( SeqCst )                    );
+
+	Ok(())
+}
+
+
+
+// Supervising after the supervisor gave up returns NurseErr::Closed.
+//
+#[ async_std::test ]
+//
+async fn closed() -> DynResult<()>
+{
+	let (supervisor, mut output) = Supervisor::new( AsyncStd, policy( RestartPolicy::one_for_one() ) );
+
+	supervisor.supervise( || async { Err( "fail" ) } )?;
+
+	assert!( matches!( output.next().await, Some( Err( Failure::Error( "fail" ) ) ) ) );
+
+	assert_eq!( Err( NurseErr::Closed ), supervisor.supervise( || async { Ok(()) } ).map( |_| () ) );
+
+	Ok(())
+}
+
+
+
+// Failure implements Error and Display.
+//
+#[ test ]
+//
+fn failure_error_trait()
+{
+	let error: Failure<NurseErr> = Failure::Error( NurseErr::Timeout );
+
+	assert_eq!( format!( "The supervised task failed: {}", NurseErr::Timeout ), error.to_string() );
+
+	let source = std::error::Error::source( &error ).expect( "source" );
+
+	assert_eq!( NurseErr::Timeout.to_string(), source.to_string() );
+
+
+	let panicked: Failure<NurseErr> = Failure::Panicked( Box::new( "boom" ) );
+
+	assert_eq!( "The supervised task panicked: boom", panicked.to_string()                   );
+	assert!   ( std::error::Error::source( &panicked ).is_none()                            );
+	assert_eq!( "The supervised task was stopped." , Failure::<NurseErr>::Stopped.to_string() );
+}
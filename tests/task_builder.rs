@@ -0,0 +1,203 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Tasks spawned with the builder yield their output with the id returned by spawn.
+// ✔ A timeout set on the builder yields NurseErr::Timeout for tasks that take too long.
+// ✔ spawn_local works on a LocalSpawnHandle.
+// ✔ Spawning on a closed nursery returns NurseErr::Closed.
+// ✔ Names show up in NurseryErrors, including in it's Display impl.
+// ✔ Names show up in the ShutdownReport for finished and dropped tasks.
+// ✔ Names show up in Outcomes.
+// ✔ running_tasks lists the ids and names of the running tasks, including those of child nurseries.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Tasks spawned with the builder yield their output with the id returned by spawn.
+//
+#[ async_std::test ]
+//
+async fn builder_spawn() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let a = nursery.task().name( "a" ).spawn( async { 1 } )?;
+	let b = nursery.task().name( "b" ).spawn( async { 2 } )?;
+
+	drop( nursery );
+
+	let mut outputs: Vec<(TaskId, usize)> = output.with_ids().collect().await;
+	outputs.sort_unstable();
+
+	assert_eq!( vec![ (a, 1), (b, 2) ], outputs );
+
+	Ok(())
+}
+
+
+
+// A timeout set on the builder yields NurseErr::Timeout for tasks that take too long.
+//
+#[ async_std::test ]
+//
+async fn builder_timeout() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.task().timeout( Duration::from_millis(10) ).spawn( async { Delay::new( Duration::from_secs(10) ).await; Ok(3) } )?;
+	nursery.task().timeout( Duration::from_secs(10)   ).spawn( async { Ok(5) } )?;
+
+	drop( nursery );
+
+	let mut outputs: Vec<Result<usize, NurseErr>> = output.collect().await;
+	outputs.sort_unstable_by_key( |out| out.is_err() );
+
+	assert_eq!( vec![ Ok(5), Err( NurseErr::Timeout ) ], outputs );
+
+	Ok(())
+}
+
+
+
+// spawn_local works on a LocalSpawnHandle.
+//
+#[ test ]
+//
+fn builder_spawn_local() -> DynResult<()>
+{
+	let exec              = TokioCt::new()?;
+	let (nursery, output) = Nursery::new( exec.clone() );
+	let rc                = Rc::new( 5 );
+
+	nursery.task().name( "local" ).spawn_local( async move { *rc } )?;
+
+	drop( nursery );
+
+	let outputs: Vec<usize> = exec.block_on( output.collect() );
+
+	assert_eq!( vec![5], outputs );
+
+	Ok(())
+}
+
+
+
+// Spawning on a closed nursery returns NurseErr::Closed.
+//
+#[ async_std::test ]
+//
+async fn builder_closed() -> DynResult<()>
+{
+	let (nursery, _output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery.close_nursery();
+
+	assert_eq!( Err( NurseErr::Closed ), nursery.task().name( "late" ).spawn( async { 1 } ) );
+
+	Ok(())
+}
+
+
+
+// Names show up in NurseryErrors, including in it's Display impl.
+//
+#[ async_std::test ]
+//
+async fn name_in_errors() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::<_, Result<usize, &str>>::new( AsyncStd );
+
+	let fail = nursery.task().name( "fetch" ).spawn( async { Err( "boom" ) } )?;
+	let slow = nursery.task().name( "slow"  ).spawn( futures::future::pending() )?;
+	let anon = nursery.nurse_with_id( futures::future::pending() )?;
+
+	drop( nursery );
+
+	let errors = output.collect_all_budget( 0 ).await.expect_err( "budget exceeded" );
+
+	assert_eq!( Some( "fetch" ), errors.name( fail ) );
+	assert_eq!( Some( "slow"  ), errors.name( slow ) );
+	assert_eq!( None           , errors.name( anon ) );
+
+	assert!( errors.to_string().contains( &format!( "task {fail} (fetch): boom" ) ) );
+
+	Ok(())
+}
+
+
+
+// Names show up in the ShutdownReport for finished and dropped tasks.
+//
+#[ async_std::test ]
+//
+async fn name_in_shutdown() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let done = nursery.task().name( "done" ).spawn( async { 1 } )?;
+	let slow = nursery.task().name( "slow" ).spawn( futures::future::pending() )?;
+
+	let report = output.shutdown( Duration::from_millis(20), &nursery ).await;
+
+	assert_eq!( report.finished, vec![ (done, 1) ] );
+	assert_eq!( report.dropped , vec![ slow      ] );
+
+	assert_eq!( Some( "done" ), report.name( done ) );
+	assert_eq!( Some( "slow" ), report.name( slow ) );
+
+	Ok(())
+}
+
+
+
+// Names show up in Outcomes.
+//
+#[ async_std::test ]
+//
+async fn name_in_outcomes() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let named = nursery.task().name( "named" ).spawn( async { 1 } )?;
+	let anon  = nursery.nurse_with_id( async { 2 } )?;
+
+	drop( nursery );
+
+	let mut outcomes: Vec<_> = output.outcomes().map( |(id, name, outcome)| (id, name, outcome.completed()) ).collect().await;
+	outcomes.sort_unstable_by_key( |(id, ..)| *id );
+
+	assert_eq!( vec![ (named, Some( Arc::from( "named" ) ), Some(1)), (anon, None, Some(2)) ], outcomes );
+
+	Ok(())
+}
+
+
+
+// running_tasks lists the ids and names of the running tasks, including those of child nurseries.
+//
+#[ async_std::test ]
+//
+async fn running_tasks() -> DynResult<()>
+{
+	let (nursery, mut output) = Nursery::<_, usize>::new( AsyncStd );
+
+	let parent = nursery.task().name( "parent" ).spawn( futures::future::pending() )?;
+	let child  = nursery.child()?;
+	let nested = child.task().name( "child" ).spawn( futures::future::pending() )?;
+
+	// Let the stream pick up the tasks and the child nursery.
+	//
+	assert!( futures::poll!( output.next() ).is_pending() );
+
+	let mut running = output.running_tasks();
+	running.sort_unstable_by_key( |(id, _)| *id );
+
+	assert_eq!( vec![ (parent, Some( Arc::from( "parent" ) )), (nested, Some( Arc::from( "child" ) )) ], running );
+
+	Ok(())
+}
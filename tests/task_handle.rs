@@ -0,0 +1,117 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Await the output of a task through it's TaskHandle.
+// ✔ Abort a single task.
+// ✔ Dropping the NurseryStream drops tasks with a TaskHandle.
+// ✔ TaskHandle for a local task.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Await the output of a task through it's TaskHandle.
+//
+#[ async_std::test ]
+//
+async fn handle_await() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let handle = nursery.nurse_with_handle( async { Delay::new( Duration::from_millis(5) ).await; 3 } )?;
+	nursery.nurse( async { 5 } )?;
+
+	drop(nursery);
+
+	assert_eq!( Some(3), handle.await );
+
+	// The output of the task with the handle doesn't show up here.
+	//
+	let outputs: Vec<usize> = output.collect().await;
+
+	assert_eq!( vec![5], outputs );
+
+	Ok(())
+}
+
+
+
+// Abort a single task.
+//
+#[ async_std::test ]
+//
+async fn handle_abort() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let handle = nursery.nurse_with_handle( async { Delay::new( Duration::from_secs(10) ).await; 3 } )?;
+	nursery.nurse( async { 5 } )?;
+
+	drop(nursery);
+
+	assert!( !handle.is_finished() );
+
+	handle.abort();
+
+	assert!( handle.is_finished() );
+
+	// Would hang for 10 seconds if the task wasn't removed.
+	//
+	let outputs: Vec<usize> = AsyncStd.timeout( Duration::from_secs(2), output.collect() ).await?;
+
+	assert_eq!( vec![5], outputs      );
+	assert_eq!( None   , handle.await );
+
+	Ok(())
+}
+
+
+
+// Dropping the NurseryStream drops tasks with a TaskHandle.
+//
+#[ async_std::test ]
+//
+async fn handle_drop_stream() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	let handle = nursery.nurse_with_handle( async { Delay::new( Duration::from_secs(10) ).await; 3 } )?;
+
+	drop(output);
+
+	assert!( handle.is_finished() );
+	assert_eq!( None, handle.await );
+
+	Ok(())
+}
+
+
+
+// TaskHandle for a local task.
+//
+#[ test ] fn handle_local() -> DynResult<()>
+{
+	let exec              = TokioCt::new()?;
+	let (nursery, output) = Nursery::new( exec.clone() );
+	let value             = Rc::new( 3 );
+
+	let handle = nursery.nurse_local_with_handle( async move { *value } )?;
+
+	drop(nursery);
+
+	exec.block_on( async
+	{
+		// Don't await the handle, the stream should still finish.
+		//
+		output.await;
+
+		assert!( handle.is_finished() );
+		assert_eq!( Some(3), handle.await );
+	});
+
+	Ok(())
+}
@@ -0,0 +1,69 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ Ids are increasing and shared between clones.
+// ✔ with_ids yields the id of the task that produced each output.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// Ids are increasing and shared between clones.
+//
+#[ async_std::test ]
+//
+async fn ids_increase() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let nursery2          = nursery.clone();
+
+	let first  = nursery .nurse_with_id( async {} )?;
+	let second = nursery2.nurse_with_id( async {} )?;
+	let third  = nursery .nurse_with_handle( async {} )?.id();
+
+	assert!( first  < second );
+	assert!( second < third  );
+
+	drop(nursery );
+	drop(nursery2);
+	output.await;
+
+	Ok(())
+}
+
+
+
+// with_ids yields the id of the task that produced each output.
+//
+#[ async_std::test ]
+//
+async fn with_ids() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let mut expected      = Vec::new();
+
+	for i in 0..5
+	{
+		let id = nursery.nurse_with_id( async move
+		{
+			Delay::new( Duration::from_millis( 5 * (5-i) ) ).await;
+			i
+		})?;
+
+		expected.push( (id, i) );
+	}
+
+	drop(nursery);
+
+	let mut outputs: Vec<(TaskId, u64)> = output.with_ids().collect().await;
+
+	outputs.sort();
+
+	assert_eq!( expected, outputs );
+
+	Ok(())
+}
@@ -0,0 +1,76 @@
+#![ cfg( feature = "implementation" ) ]
+
+// Tested:
+//
+// ✔ nurse_timeout yields NurseErr::Timeout for tasks that take too long.
+// ✔ Tasks still running at the deadline of the nursery yield NurseErr::Timeout.
+// ✔ Spawning after the deadline returns NurseErr::Timeout.
+//
+#![ cfg(not( target_arch = "wasm32" )) ]
+
+mod common;
+use common::{ *, import::* };
+
+
+
+// nurse_timeout yields NurseErr::Timeout for tasks that take too long.
+//
+#[ async_std::test ]
+//
+async fn nurse_timeout() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.nurse_timeout( Duration::from_millis(10), async { Delay::new( Duration::from_secs(10) ).await; Ok(3) } )?;
+	nursery.nurse_timeout( Duration::from_secs(10)  , async { Ok(5) } )?;
+
+	drop(nursery);
+
+	let mut outputs: Vec<Result<usize, NurseErr>> = output.collect().await;
+	outputs.sort_unstable_by_key( |out| out.is_err() );
+
+	assert_eq!( vec![ Ok(5), Err( NurseErr::Timeout ) ], outputs );
+
+	Ok(())
+}
+
+
+
+// Tasks still running at the deadline of the nursery yield NurseErr::Timeout.
+//
+#[ async_std::test ]
+//
+async fn deadline() -> DynResult<()>
+{
+	let (nursery, output) = Nursery::with_deadline( AsyncStd, Duration::from_millis(10) );
+
+	nursery.nurse( async { Delay::new( Duration::from_secs(10) ).await; Ok(3) } )?;
+	nursery.nurse( async { Delay::new( Duration::from_secs(10) ).await; Ok(4) } )?;
+	nursery.nurse( async { Ok(5) } )?;
+
+	drop(nursery);
+
+	let mut outputs: Vec<Result<usize, NurseErr>> = output.collect().await;
+	outputs.sort_unstable_by_key( |out| out.is_err() );
+
+	assert_eq!( vec![ Ok(5), Err( NurseErr::Timeout ), Err( NurseErr::Timeout ) ], outputs );
+
+	Ok(())
+}
+
+
+
+// Spawning after the deadline returns NurseErr::Timeout.
+//
+#[ async_std::test ]
+//
+async fn deadline_passed() -> DynResult<()>
+{
+	let (nursery, _output) = Nursery::<_, Result<(), NurseErr>>::with_deadline( AsyncStd, Duration::from_millis(10) );
+
+	Delay::new( Duration::from_millis(50) ).await;
+
+	assert_eq!( Err( NurseErr::Timeout ), nursery.nurse( async { Ok(()) } ) );
+
+	Ok(())
+}
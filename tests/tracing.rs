@@ -5,13 +5,71 @@
 // ✔ Verify close_nursery works.
 // - test Sink impl.
 // ✔ test forwarding of traits on tracing types.
+// ✔ Tasks run in a span within the span of their nursery, child nurseries within the span of their parent.
+// ✔ Events are emitted when tasks are spawned, complete or are dropped and when the nursery is cancelled.
+// ✔ An event is emitted when a task panics.
+// ✔ The name and criticality from the TaskBuilder are recorded in the span of the task.
 //
 #![ cfg(all( not(target_arch = "wasm32"), feature = "tracing" )) ]
 
 mod common;
 use common::{ *, import::* };
 use tracing_futures::Instrument;
-use tracing_crate::info_span;
+use tracing_crate::{ info_span, field::{ Field, Visit }, span::{ Attributes, Id }, Event, Subscriber };
+use tracing_subscriber::{ layer::{ Context, Layer, SubscriberExt }, registry::LookupSpan };
+use std::sync::Mutex;
+
+
+
+// Records spans as "name(fields) < parent" and events as "span: message".
+//
+#[ derive( Clone, Default ) ]
+//
+struct Recorder
+{
+	spans : Arc< Mutex<Vec<String>> > ,
+	events: Arc< Mutex<Vec<String>> > ,
+}
+
+
+#[ derive( Default ) ]
+//
+struct Fields( Vec<String> );
+
+
+impl Visit for Fields
+{
+	fn record_debug( &mut self, field: &Field, value: &dyn std::fmt::Debug )
+	{
+		self.0.push( format!( "{}={:?}", field.name(), value ) );
+	}
+}
+
+
+impl<S> Layer<S> for Recorder where S: Subscriber + for<'a> LookupSpan<'a>
+{
+	fn on_new_span( &self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S> )
+	{
+		let mut fields = Fields::default();
+		attrs.record( &mut fields );
+
+		let span   = ctx.span( id ).unwrap();
+		let parent = span.parent().map( |p| p.name() ).unwrap_or( "-" );
+
+		self.spans.lock().unwrap().push( format!( "{}({}) < {}", span.name(), fields.0.join( " " ), parent ) );
+	}
+
+
+	fn on_event( &self, event: &Event<'_>, ctx: Context<'_, S> )
+	{
+		let mut fields = Fields::default();
+		event.record( &mut fields );
+
+		let span = ctx.event_span( event ).map( |s| s.name() ).unwrap_or( "-" );
+
+		self.events.lock().unwrap().push( format!( "{}: {}", span, fields.0.join( " " ) ) );
+	}
+}
 
 
 // Verify close_nursery works.
@@ -52,3 +110,120 @@ use tracing_crate::info_span;
 
 	Ok(())
 }
+
+
+
+// Tasks run in a span within the span of their nursery, child nurseries within the span of their parent.
+//
+#[async_std::test] async fn spans() -> DynResult<()>
+{
+	let recorder = Recorder::default();
+	let _guard   = tracing_crate::subscriber::set_default( tracing_subscriber::registry().with( recorder.clone() ) );
+
+	let (nursery, output) = Nursery::new( AsyncStd );
+	let child             = nursery.child()?;
+
+	nursery.nurse( async { 1 } )?;
+	child  .nurse( async { 2 } )?;
+
+	drop( nursery );
+	drop( child   );
+	output.await;
+
+	let spans = recorder.spans.lock().unwrap().clone();
+
+	assert_eq!( 4, spans.len() );
+	assert_eq!( "nursery() < -"      , spans[0] );
+	assert_eq!( "nursery() < nursery", spans[1] );
+
+	assert!( spans[2].starts_with( "task(id=0 critical=true location=tests/tracing.rs:" ) );
+	assert!( spans[2].ends_with  ( ") < nursery"                                        ) );
+	assert!( spans[3].starts_with( "task(id=1 critical=true location=tests/tracing.rs:" ) );
+
+	Ok(())
+}
+
+
+
+// Events are emitted when tasks are spawned, complete or are dropped and when the nursery is cancelled.
+//
+#[async_std::test] async fn events() -> DynResult<()>
+{
+	let recorder = Recorder::default();
+	let _guard   = tracing_crate::subscriber::set_default( tracing_subscriber::registry().with( recorder.clone() ) );
+
+	let (nursery, mut output) = Nursery::new( AsyncStd );
+
+	nursery.nurse( async { 1 } )?;
+	nursery.nurse( futures::future::pending() )?;
+
+	assert_eq!( Some(1), output.next().await );
+
+	output.cancel();
+	drop( output );
+
+	let events = recorder.events.lock().unwrap().clone();
+
+	assert_eq!
+	(
+		vec!
+		[
+			"task: message=task spawned"                   ,
+			"task: message=task spawned"                   ,
+			"task: message=task completed"                 ,
+			"nursery: message=nursery cancelled"           ,
+			"task: message=task dropped before it finished",
+		],
+
+		events
+	);
+
+	Ok(())
+}
+
+
+
+// An event is emitted when a task panics.
+//
+#[async_std::test] async fn panic_event() -> DynResult<()>
+{
+	let recorder = Recorder::default();
+	let _guard   = tracing_crate::subscriber::set_default( tracing_subscriber::registry().with( recorder.clone() ) );
+
+	let (mut nursery, output) = Nursery::<_, usize>::new( AsyncStd );
+
+	nursery.set_panic_policy( PanicPolicy::CancelSiblings );
+	nursery.nurse( async { panic!( "boom" ) } )?;
+
+	drop( nursery );
+	output.await;
+
+	let events = recorder.events.lock().unwrap().clone();
+
+	assert_eq!( vec![ "task: message=task spawned", "task: message=task panicked" ], events );
+
+	Ok(())
+}
+
+
+
+// The name and criticality from the TaskBuilder are recorded in the span of the task.
+//
+#[async_std::test] async fn task_builder() -> DynResult<()>
+{
+	let recorder = Recorder::default();
+	let _guard   = tracing_crate::subscriber::set_default( tracing_subscriber::registry().with( recorder.clone() ) );
+
+	let (nursery, output) = Nursery::new( AsyncStd );
+
+	nursery.task().name( "heartbeat" ).critical( false ).spawn( async { 1 } )?;
+
+	drop( nursery );
+	output.await;
+
+	let spans = recorder.spans.lock().unwrap().clone();
+
+	assert!( spans[1].starts_with( "task(id=0 name=\"heartbeat\" critical=false location=tests/tracing.rs:" ) );
+
+	Ok(())
+}